@@ -0,0 +1,39 @@
+/* st colors generated by lulezojne */
+static const char *colorname[] = {
+	/* 8 normal colors */
+	"{{hex ansi.main.black}}",
+	"{{hex ansi.main.red}}",
+	"{{hex ansi.main.green}}",
+	"{{hex ansi.main.yellow}}",
+	"{{hex ansi.main.blue}}",
+	"{{hex ansi.main.magenta}}",
+	"{{hex ansi.main.cyan}}",
+	"{{hex ansi.main.white}}",
+
+	/* 8 bright colors */
+	"{{hex ansi.main.bright_black}}",
+	"{{hex ansi.main.bright_red}}",
+	"{{hex ansi.main.bright_green}}",
+	"{{hex ansi.main.bright_yellow}}",
+	"{{hex ansi.main.bright_blue}}",
+	"{{hex ansi.main.bright_magenta}}",
+	"{{hex ansi.main.bright_cyan}}",
+	"{{hex ansi.main.bright_white}}",
+
+	[255] = 0,
+
+	/* more colors can be added after 255 to use with DefaultXX */
+	"{{hex semantic.cursor}}",
+	"{{hex semantic.cursor_text}}",
+	"{{hex semantic.foreground}}",
+	"{{hex semantic.background}}",
+};
+
+/*
+ * Default colors (colorname index)
+ * foreground, background, cursor, reverse cursor
+ */
+unsigned int defaultfg = 258;
+unsigned int defaultbg = 259;
+unsigned int defaultcs = 256;
+static unsigned int defaultrcs = 257;
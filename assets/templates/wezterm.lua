@@ -0,0 +1,32 @@
+-- WezTerm colors generated by lulezojne
+return {
+  foreground = "{{hex semantic.foreground}}",
+  background = "{{hex semantic.background}}",
+  cursor_bg = "{{hex semantic.cursor}}",
+  cursor_fg = "{{hex semantic.cursor_text}}",
+  cursor_border = "{{hex semantic.cursor}}",
+  selection_fg = "{{hex semantic.selection_foreground}}",
+  selection_bg = "{{hex semantic.selection_background}}",
+  scrollbar_thumb = "{{hex semantic.surface}}",
+  split = "{{hex semantic.border}}",
+  ansi = {
+    "{{hex ansi.main.black}}",
+    "{{hex ansi.main.red}}",
+    "{{hex ansi.main.green}}",
+    "{{hex ansi.main.yellow}}",
+    "{{hex ansi.main.blue}}",
+    "{{hex ansi.main.magenta}}",
+    "{{hex ansi.main.cyan}}",
+    "{{hex ansi.main.white}}",
+  },
+  brights = {
+    "{{hex ansi.main.bright_black}}",
+    "{{hex ansi.main.bright_red}}",
+    "{{hex ansi.main.bright_green}}",
+    "{{hex ansi.main.bright_yellow}}",
+    "{{hex ansi.main.bright_blue}}",
+    "{{hex ansi.main.bright_magenta}}",
+    "{{hex ansi.main.bright_cyan}}",
+    "{{hex ansi.main.bright_white}}",
+  },
+}
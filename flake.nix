@@ -100,7 +100,7 @@
           pname = "lulezojne";
           version = "0.1.0";
           src = self;
-          cargoHash = "sha256-xAxR84YrAqikBd2fmVw9IGaec/FAgGtn6hXWDW6Rt3g=";
+          cargoLock.lockFile = ./Cargo.lock;
           meta = {
             description = "Image to ANSI color converter heavily inspired by lule";
             homepage = "https://github.com/haras-unicorn/lulezojne";
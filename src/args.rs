@@ -23,6 +23,38 @@ pub enum Args {
     /// The format in which to print
     #[arg(long, short, value_enum, default_value = "list")]
     format: Format,
+
+    /// When to color terminal output
+    #[arg(long, value_enum, default_value = "auto")]
+    color: Color,
+
+    /// Which sections to print in terminal formats
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "ansi")]
+    show: Vec<Show>,
+
+    /// How to sort source colors
+    #[arg(long, value_enum, default_value = "weight")]
+    sort: Sort,
+
+    /// Where to write file formats like png or svg - defaults to stdout
+    #[arg(long, short)]
+    output: Option<String>,
+
+    /// Include a thumbnail of the image in file formats like png or svg
+    #[arg(long, short)]
+    thumbnail: bool,
+
+    /// Color to calculate contrast ratios against in formats like html
+    #[arg(
+      long,
+      default_value = "black",
+      value_parser = clap::builder::PossibleValuesParser::new(SLOTS)
+    )]
+    background: String,
+
+    /// Simulate a color vision deficiency in the preview format
+    #[arg(long, value_enum)]
+    simulate: Option<Deficiency>,
   },
 }
 
@@ -36,11 +68,56 @@ pub struct ConfigArgs {
 #[derive(Debug, Clone, clap::Args)]
 pub struct GenerationArgs {
   /// Image to take prominent colors from
-  pub image: String,
+  #[arg(required_unless_present_any = ["colors", "palette", "theme"])]
+  pub image: Option<String>,
+
+  /// Take colors from a list like '#1d2021,#cc241d' instead of an image
+  #[arg(long, conflicts_with_all = ["image", "palette", "theme"])]
+  pub colors: Option<String>,
+
+  /// Take colors from a file of hex colors or a GIMP .gpl palette
+  #[arg(long, conflicts_with_all = ["image", "colors", "theme"])]
+  pub palette: Option<String>,
+
+  /// Take colors from a Base16, Xresources, kitty, Alacritty, Windows Terminal or iTerm theme
+  #[arg(long, conflicts_with_all = ["image", "colors", "palette"])]
+  pub theme: Option<String>,
+
+  /// How to use colors of a theme
+  #[arg(long, value_enum, default_value = "direct")]
+  pub import: Import,
 
   /// Backend to use for generation of prominent colors
   #[arg(long, short, value_enum, default_value = "neoquant")]
   pub backend: Backend,
+
+  /// Pin an ANSI color to a fixed color like red=#cc241d
+  #[arg(long = "set", value_name = "NAME=COLOR")]
+  pub overrides: Vec<String>,
+
+  /// Whether to generate a dark or light theme
+  #[arg(long, value_enum, default_value = "dark")]
+  pub mode: Mode,
+}
+
+#[derive(Debug, Clone, Default, clap::ValueEnum)]
+pub enum Import {
+  /// Use the theme colors as they are for ANSI and semantic colors
+  #[default]
+  Direct,
+  /// Extrapolate everything from the theme colors like from an image
+  Extrapolate,
+}
+
+#[derive(Debug, Clone, Default, clap::ValueEnum)]
+pub enum Mode {
+  /// Dark background with light foregrounds
+  #[default]
+  Dark,
+  /// Light background with dark foregrounds
+  Light,
+  /// Pick based on the average lightness of the image
+  Auto,
 }
 
 #[derive(Debug, Clone, Default, clap::ValueEnum)]
@@ -67,8 +144,98 @@ pub enum Format {
   List,
   /// Grid with names of colors in foreground and color-coded background
   Grid,
+  /// Fake terminal session showing how the colors look in practice
+  Preview,
+  /// Self-contained HTML page with the image, source palette and contrast ratios
+  Html,
+  /// PNG swatch sheet with names and hex codes of colors
+  Png,
+  /// SVG swatch sheet with names and hex codes of colors
+  Svg,
+  /// Base16 scheme YAML for base16 templates
+  Base16,
+  /// Base24 scheme YAML for base24 templates
+  Base24,
+  /// GIMP and Inkscape palette
+  Gpl,
+  /// Adobe Swatch Exchange
+  Ase,
+  /// Adobe Photoshop swatches
+  Aco,
+  /// Paint.NET palette
+  PaintNet,
+  /// KDE palette
+  Kde,
+  /// LibreOffice palette
+  Soc,
+}
+
+#[derive(Debug, Clone, Default, clap::ValueEnum)]
+pub enum Color {
+  /// Always use truecolor escapes
+  Always,
+  /// Never use color escapes
+  Never,
+  /// Detect color support from the terminal and environment
+  #[default]
+  Auto,
+  /// Downsample to the xterm 256 color palette
+  #[value(name = "256")]
+  Indexed,
+  /// Downsample to the 16 basic terminal colors
+  #[value(name = "16")]
+  Basic,
+}
+
+#[derive(Debug, Clone, Default, clap::ValueEnum)]
+pub enum Show {
+  /// Extrapolated ANSI colors
+  #[default]
+  Ansi,
+  /// Raw colors from the backend and which ANSI colors were mixed from them
+  Source,
+  /// Roles like background, foreground, cursor and accent
+  Semantic,
 }
 
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum Deficiency {
+  /// Missing red cones
+  Protan,
+  /// Missing green cones
+  Deutan,
+  /// Missing blue cones
+  Tritan,
+}
+
+#[derive(Debug, Clone, Default, clap::ValueEnum)]
+pub enum Sort {
+  /// Most prominent first
+  #[default]
+  Weight,
+  /// Around the color wheel
+  Hue,
+}
+
+const SLOTS: [&str; 16] = [
+  "black",
+  "red",
+  "green",
+  "blue",
+  "cyan",
+  "yellow",
+  "magenta",
+  "white",
+  "bright_black",
+  "bright_red",
+  "bright_green",
+  "bright_blue",
+  "bright_cyan",
+  "bright_yellow",
+  "bright_magenta",
+  "bright_white",
+];
+
 // NOTE: try_parse triggers anyhow
 pub fn parse() -> Args {
   Args::parse()
@@ -27,23 +27,34 @@ pub async fn prominent(
     config.max_colors,
   )?;
 
-  Ok(super::Colors {
-    palette: palette
-      .drain(0..)
-      .map(
-        |color_thief::Color {
-           r: red,
-           g: green,
-           b: blue,
-         }| {
-          super::Rgba {
-            red,
-            green,
-            blue,
-            alpha: 1.0,
-          }
-        },
-      )
-      .collect(),
-  })
+  let palette = palette
+    .drain(0..)
+    .map(
+      |color_thief::Color {
+         r: red,
+         g: green,
+         b: blue,
+       }| {
+        super::Rgba {
+          red,
+          green,
+          blue,
+          alpha: 1.0,
+        }
+      },
+    )
+    .collect::<Vec<_>>();
+  let weights = super::weigh(
+    bytes
+      .chunks_exact(3)
+      .filter_map(|pixel| match pixel {
+        [red, green, blue] => Some([*red, *green, *blue]),
+        _ => None,
+      })
+      .collect::<Vec<_>>()
+      .as_slice(),
+    palette.as_slice(),
+  );
+
+  Ok(super::Colors { palette, weights })
 }
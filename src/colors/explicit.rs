@@ -0,0 +1,74 @@
+// NOTE: palettes given directly instead of extracted from an image
+// NOTE: every color weighs the same since there is no image to cover
+
+#[tracing::instrument]
+pub fn from_list(list: &str) -> anyhow::Result<super::Colors> {
+  let palette = list
+    .split(|char: char| char == ',' || char.is_whitespace())
+    .filter(|color| !color.is_empty())
+    .map(super::hex)
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  colors(palette)
+}
+
+// NOTE: GIMP palettes by extension otherwise hex colors separated by whitespace
+#[tracing::instrument]
+pub async fn from_file(path: String) -> anyhow::Result<super::Colors> {
+  let content = tokio::fs::read_to_string(&path).await?;
+  let is_gpl = std::path::Path::new(&path)
+    .extension()
+    .is_some_and(|extension| extension.eq_ignore_ascii_case("gpl"));
+
+  if is_gpl {
+    colors(gpl(&content)?)
+  } else {
+    from_list(&content)
+  }
+}
+
+fn colors(palette: Vec<super::Rgba>) -> anyhow::Result<super::Colors> {
+  if palette.is_empty() {
+    return Err(anyhow::anyhow!("No colors given"));
+  }
+
+  let weight = 1.0f32 / palette.len() as f32;
+  Ok(super::Colors {
+    weights: vec![weight; palette.len()],
+    palette,
+  })
+}
+
+fn gpl(content: &str) -> anyhow::Result<Vec<super::Rgba>> {
+  let mut lines = content.lines();
+  if lines.next().map(str::trim) != Some("GIMP Palette") {
+    return Err(anyhow::anyhow!("Missing GIMP Palette header"));
+  }
+
+  let mut palette = Vec::new();
+  for line in lines {
+    let line = line.trim();
+    if line.is_empty()
+      || line.starts_with('#')
+      || line.starts_with("Name:")
+      || line.starts_with("Columns:")
+    {
+      continue;
+    }
+
+    let mut channels = line.split_whitespace().map(str::parse::<u8>);
+    match (channels.next(), channels.next(), channels.next()) {
+      (Some(Ok(red)), Some(Ok(green)), Some(Ok(blue))) => {
+        palette.push(super::Rgba {
+          red,
+          green,
+          blue,
+          alpha: 1.0f32,
+        })
+      }
+      _ => return Err(anyhow::anyhow!("Invalid GIMP Palette line {}", line)),
+    }
+  }
+
+  Ok(palette)
+}
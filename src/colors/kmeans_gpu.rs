@@ -75,6 +75,10 @@ pub async fn prominent(
           }
         })
         .collect(),
+      weights: super::count(
+        kmeans.indices.iter().map(|index| (*index).into()),
+        kmeans.centroids.len(),
+      ),
     })
   })
   .await?
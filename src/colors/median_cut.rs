@@ -13,7 +13,18 @@ pub async fn prominent(
   config: MedianCutConfig,
 ) -> anyhow::Result<super::Colors> {
   tokio::spawn(async move {
-    let bucket = iris_lib::color_bucket::ColorBucket::from_image(path.as_str());
+    let image = image::io::Reader::open(path.as_str())?.decode()?.to_rgba8();
+    let bucket = iris_lib::color_bucket::ColorBucket::from_pixels(
+      image
+        .pixels()
+        .map(|image::Rgba([r, g, b, a])| iris_lib::color::Color {
+          r: *r,
+          g: *g,
+          b: *b,
+          a: *a,
+        })
+        .collect(),
+    );
     match bucket {
       None => Err(anyhow::anyhow!(format!(
         "Failed creating buckets from {path}"
@@ -35,17 +46,25 @@ pub async fn prominent(
           ) + "\n"
         };
 
-        Ok(super::Colors {
-          palette: palette
-            .iter()
-            .map(|iris_lib::color::Color { r, g, b, a }| super::Rgba {
-              red: *r,
-              green: *g,
-              blue: *b,
-              alpha: (Into::<f32>::into(*a) / 255.0f32),
-            })
-            .collect(),
-        })
+        let palette = palette
+          .iter()
+          .map(|iris_lib::color::Color { r, g, b, a }| super::Rgba {
+            red: *r,
+            green: *g,
+            blue: *b,
+            alpha: (Into::<f32>::into(*a) / 255.0f32),
+          })
+          .collect::<Vec<_>>();
+        let weights = super::weigh(
+          image
+            .pixels()
+            .map(|image::Rgba([r, g, b, _])| [*r, *g, *b])
+            .collect::<Vec<_>>()
+            .as_slice(),
+          palette.as_slice(),
+        );
+
+        Ok(super::Colors { palette, weights })
       }
     }
   })
@@ -1,13 +1,18 @@
 pub mod colorthief;
+pub mod explicit;
 pub mod kmeans;
 pub mod kmeans_gpu;
 pub mod median_cut;
 pub mod neoquant;
 pub mod scolorq;
+pub mod theme;
 
 #[derive(Debug, Clone)]
 pub struct Colors {
   pub palette: Vec<Rgba>,
+
+  // NOTE: share of the image covered by each palette color in the same order
+  pub weights: Vec<f32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -17,3 +22,70 @@ pub struct Rgba {
   pub blue: u8,
   pub alpha: f32,
 }
+
+// NOTE: "#rgb", "#rrggbb" or "0xrrggbb" like in configs and theme files
+pub fn hex(color: &str) -> anyhow::Result<Rgba> {
+  let hex = color
+    .trim()
+    .trim_matches(['"', '\''])
+    .trim_start_matches('#')
+    .trim_start_matches("0x");
+  let channel = |index: usize, length: usize| {
+    let channel = u8::from_str_radix(&hex[index..index + length], 16)?;
+    anyhow::Ok(if length == 1 { channel * 17 } else { channel })
+  };
+
+  let (red, green, blue) = match hex.len() {
+    3 if hex.is_ascii() => (channel(0, 1)?, channel(1, 1)?, channel(2, 1)?),
+    6 if hex.is_ascii() => (channel(0, 2)?, channel(2, 2)?, channel(4, 2)?),
+    _ => return Err(anyhow::anyhow!("Invalid color {}", color)),
+  };
+
+  Ok(Rgba {
+    red,
+    green,
+    blue,
+    alpha: 1.0f32,
+  })
+}
+
+fn count(indices: impl Iterator<Item = usize>, colors: usize) -> Vec<f32> {
+  let mut counts = vec![0usize; colors];
+  let mut total = 0usize;
+  for index in indices {
+    if let Some(count) = counts.get_mut(index) {
+      *count += 1;
+      total += 1;
+    }
+  }
+
+  counts
+    .iter()
+    .map(|count| *count as f32 / total.max(1) as f32)
+    .collect()
+}
+
+// NOTE: for backends that don't tell us which pixel went where
+fn weigh(pixels: &[[u8; 3]], palette: &[Rgba]) -> Vec<f32> {
+  use rayon::prelude::*;
+
+  let step = (pixels.len() / 65536).max(1);
+  let indices = pixels
+    .par_iter()
+    .step_by(step)
+    .filter_map(|[red, green, blue]| {
+      palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, color)| {
+          let red = i32::from(*red) - i32::from(color.red);
+          let green = i32::from(*green) - i32::from(color.green);
+          let blue = i32::from(*blue) - i32::from(color.blue);
+          red * red + green * green + blue * blue
+        })
+        .map(|(index, _)| index)
+    })
+    .collect::<Vec<_>>();
+
+  count(indices.into_iter(), palette.len())
+}
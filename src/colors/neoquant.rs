@@ -54,7 +54,19 @@ pub async fn prominent(
       ) + "\n"
     };
 
-    Ok(super::Colors { palette })
+    let weights = super::weigh(
+      pixels
+        .chunks_exact(4)
+        .filter_map(|pixel| match pixel {
+          [red, green, blue, _] => Some([*red, *green, *blue]),
+          _ => None,
+        })
+        .collect::<Vec<_>>()
+        .as_slice(),
+      palette.as_slice(),
+    );
+
+    Ok(super::Colors { palette, weights })
   })
   .await?
 }
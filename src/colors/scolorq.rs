@@ -104,7 +104,12 @@ pub async fn prominent(
       ) + "\n"
     };
 
-    Ok(super::Colors { palette })
+    let weights = super::count(
+      quantized.iter().map(|index| (*index).into()),
+      palette.len(),
+    );
+
+    Ok(super::Colors { palette, weights })
   })
   .await?
 }
@@ -0,0 +1,450 @@
+use serde_json::Value;
+
+// NOTE: colors of an existing terminal theme with ANSI colors in xterm order
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+  pub ansi: [Option<super::Rgba>; 16],
+  pub background: Option<super::Rgba>,
+  pub foreground: Option<super::Rgba>,
+  pub cursor: Option<super::Rgba>,
+}
+
+// NOTE: base16 to xterm order like in base16-shell
+const BASE16: [&str; 16] = [
+  "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C",
+  "base05", "base03", "base08", "base0B", "base0A", "base0D", "base0E",
+  "base0C", "base07",
+];
+
+const ALACRITTY: [&str; 8] = [
+  "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+const WINDOWS_TERMINAL: [&str; 16] = [
+  "black",
+  "red",
+  "green",
+  "yellow",
+  "blue",
+  "purple",
+  "cyan",
+  "white",
+  "brightBlack",
+  "brightRed",
+  "brightGreen",
+  "brightYellow",
+  "brightBlue",
+  "brightPurple",
+  "brightCyan",
+  "brightWhite",
+];
+
+impl Theme {
+  // NOTE: every theme color weighs the same for re-extrapolation
+  pub fn colors(&self) -> anyhow::Result<super::Colors> {
+    let palette = self
+      .ansi
+      .iter()
+      .chain([&self.background, &self.foreground])
+      .flatten()
+      .cloned()
+      .collect::<Vec<_>>();
+    if palette.is_empty() {
+      return Err(anyhow::anyhow!("No colors in theme"));
+    }
+
+    let weight = 1.0f32 / palette.len() as f32;
+    Ok(super::Colors {
+      weights: vec![weight; palette.len()],
+      palette,
+    })
+  }
+}
+
+// NOTE: Base16 YAML, Xresources, kitty, Alacritty, Windows Terminal or iTerm
+#[tracing::instrument]
+pub async fn read(path: String) -> anyhow::Result<Theme> {
+  let extension = std::path::Path::new(&path)
+    .extension()
+    .map(|extension| extension.to_string_lossy().to_lowercase())
+    .unwrap_or_default();
+
+  if extension == "itermcolors" || extension == "plist" {
+    let bytes = tokio::fs::read(&path).await?;
+    return structured(plist::from_bytes::<Value>(&bytes)?);
+  }
+
+  let content = tokio::fs::read_to_string(&path).await?;
+  match extension.as_str() {
+    "yaml" | "yml" => structured(serde_norway::from_str::<Value>(&content)?),
+    "toml" => structured(toml::from_str::<Value>(&content)?),
+    "json" => structured(serde_json::from_str::<Value>(&content)?),
+    _ => lines(&content),
+  }
+}
+
+fn structured(value: Value) -> anyhow::Result<Theme> {
+  // NOTE: newer base16 schemes nest colors under palette
+  let base16 = value.get("palette").unwrap_or(&value);
+  if base16.get("base00").is_some() {
+    return self::base16(base16);
+  }
+
+  if let Some(colors) = value.get("colors") {
+    return alacritty(colors);
+  }
+
+  // NOTE: settings files can hold many schemes and we can't guess which one
+  if let Some(schemes) =
+    value.get("schemes").and_then(|schemes| schemes.as_array())
+  {
+    return match schemes.as_slice() {
+      [scheme] => windows_terminal(scheme),
+      [] => Err(anyhow::anyhow!("No schemes in theme")),
+      schemes => Err(anyhow::anyhow!(
+        "Theme has {} schemes {} - keep only one",
+        schemes.len(),
+        schemes
+          .iter()
+          .filter_map(|scheme| scheme.get("name"))
+          .filter_map(|name| name.as_str())
+          .collect::<Vec<_>>()
+          .join(", ")
+      )),
+    };
+  }
+  if value.get("brightBlack").is_some() {
+    return windows_terminal(&value);
+  }
+
+  if value.get("Ansi 0 Color").is_some()
+    || value.get("Background Color").is_some()
+  {
+    return iterm(&value);
+  }
+
+  Err(anyhow::anyhow!("Unknown theme format"))
+}
+
+fn base16(value: &Value) -> anyhow::Result<Theme> {
+  let color = |name: &str| -> anyhow::Result<Option<super::Rgba>> {
+    // NOTE: some schemes use lowercase hex digits in keys
+    value
+      .get(name)
+      .or_else(|| value.get(name.to_lowercase()))
+      .and_then(|color| color.as_str())
+      .map(super::hex)
+      .transpose()
+  };
+
+  let mut theme = Theme {
+    background: color("base00")?,
+    foreground: color("base05")?,
+    cursor: color("base05")?,
+    ..Default::default()
+  };
+  for (index, name) in BASE16.iter().enumerate() {
+    theme.ansi[index] = color(name)?;
+  }
+
+  Ok(theme)
+}
+
+fn alacritty(colors: &Value) -> anyhow::Result<Theme> {
+  let color = |section: &str, name: &str| -> anyhow::Result<_> {
+    colors
+      .get(section)
+      .and_then(|section| section.get(name))
+      .and_then(|color| color.as_str())
+      .map(super::hex)
+      .transpose()
+  };
+
+  let mut theme = Theme {
+    background: color("primary", "background")?,
+    foreground: color("primary", "foreground")?,
+    cursor: color("cursor", "cursor")?,
+    ..Default::default()
+  };
+  for (index, name) in ALACRITTY.iter().enumerate() {
+    theme.ansi[index] = color("normal", name)?;
+    theme.ansi[index + 8] = color("bright", name)?;
+  }
+
+  Ok(theme)
+}
+
+fn windows_terminal(scheme: &Value) -> anyhow::Result<Theme> {
+  let color = |name: &str| -> anyhow::Result<_> {
+    scheme
+      .get(name)
+      .and_then(|color| color.as_str())
+      .map(super::hex)
+      .transpose()
+  };
+
+  let mut theme = Theme {
+    background: color("background")?,
+    foreground: color("foreground")?,
+    cursor: color("cursorColor")?,
+    ..Default::default()
+  };
+  for (index, name) in WINDOWS_TERMINAL.iter().enumerate() {
+    theme.ansi[index] = color(name)?;
+  }
+
+  Ok(theme)
+}
+
+fn iterm(value: &Value) -> anyhow::Result<Theme> {
+  let color = |name: &str| {
+    let color = value.get(name)?;
+    let component = |component: &str| {
+      color
+        .get(format!("{component} Component"))
+        .and_then(|component| component.as_f64())
+        .map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u8)
+    };
+    Some(super::Rgba {
+      red: component("Red")?,
+      green: component("Green")?,
+      blue: component("Blue")?,
+      alpha: 1.0f32,
+    })
+  };
+
+  let mut theme = Theme {
+    background: color("Background Color"),
+    foreground: color("Foreground Color"),
+    cursor: color("Cursor Color"),
+    ..Default::default()
+  };
+  for index in 0..16 {
+    theme.ansi[index] = color(format!("Ansi {index} Color").as_str());
+  }
+
+  Ok(theme)
+}
+
+// NOTE: kitty "color0 #000000" and Xresources "*.color0: #000000" lines
+fn lines(content: &str) -> anyhow::Result<Theme> {
+  // NOTE: Xresources themes often name colors with defines
+  let defines = content
+    .lines()
+    .filter_map(|line| line.trim().strip_prefix("#define"))
+    .filter_map(|define| define.trim().split_once(char::is_whitespace))
+    .map(|(name, value)| (name.trim(), value.trim()))
+    .collect::<std::collections::HashMap<_, _>>();
+
+  let mut theme = Theme::default();
+  for line in content.lines() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('!') || line.starts_with('#') {
+      continue;
+    }
+
+    let Some((key, value)) = line
+      .split_once(':')
+      .or_else(|| line.split_once(char::is_whitespace))
+    else {
+      continue;
+    };
+    let key = key.trim().rsplit(['.', '*']).next().unwrap_or_default();
+    let value = value.trim();
+    let value = defines.get(value).copied().unwrap_or(value);
+    // NOTE: values like "cursor none" in kitty themes are not colors
+    let Ok(color) = super::hex(value) else {
+      continue;
+    };
+
+    let slot = match key {
+      "background" => &mut theme.background,
+      "foreground" => &mut theme.foreground,
+      "cursor" | "cursorColor" => &mut theme.cursor,
+      key => match key
+        .strip_prefix("color")
+        .and_then(|index| index.parse::<usize>().ok())
+        .and_then(|index| theme.ansi.get_mut(index))
+      {
+        Some(slot) => slot,
+        None => continue,
+      },
+    };
+    *slot = Some(color);
+  }
+
+  if theme.ansi.iter().all(Option::is_none) {
+    return Err(anyhow::anyhow!("No colors in theme"));
+  }
+
+  Ok(theme)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hex(color: &Option<super::super::Rgba>) -> Option<String> {
+    color.as_ref().map(|color| {
+      format!("#{:02x}{:02x}{:02x}", color.red, color.green, color.blue)
+    })
+  }
+
+  #[test]
+  fn base16_yaml() -> anyhow::Result<()> {
+    let theme = structured(serde_norway::from_str::<Value>(
+      r#"
+scheme: "Gruvbox"
+palette:
+  base00: "282828"
+  base03: "665c54"
+  base05: "d5c4a1"
+  base07: "fbf1c7"
+  base08: "fb4934"
+  base0A: "fabd2f"
+  base0B: "b8bb26"
+  base0C: "8ec07c"
+  base0D: "83a598"
+  base0E: "d3869b"
+"#,
+    )?)?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#282828"));
+    assert_eq!(hex(&theme.foreground).as_deref(), Some("#d5c4a1"));
+    assert_eq!(hex(&theme.ansi[1]).as_deref(), Some("#fb4934"));
+    assert_eq!(hex(&theme.ansi[8]).as_deref(), Some("#665c54"));
+    assert_eq!(hex(&theme.ansi[15]).as_deref(), Some("#fbf1c7"));
+    Ok(())
+  }
+
+  #[test]
+  fn alacritty_toml() -> anyhow::Result<()> {
+    let theme = structured(toml::from_str::<Value>(
+      r##"
+[colors.primary]
+background = "#282828"
+foreground = "0xebdbb2"
+
+[colors.cursor]
+cursor = "#928374"
+
+[colors.normal]
+red = "#cc241d"
+
+[colors.bright]
+white = "#ebdbb2"
+"##,
+    )?)?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#282828"));
+    assert_eq!(hex(&theme.foreground).as_deref(), Some("#ebdbb2"));
+    assert_eq!(hex(&theme.cursor).as_deref(), Some("#928374"));
+    assert_eq!(hex(&theme.ansi[1]).as_deref(), Some("#cc241d"));
+    assert_eq!(hex(&theme.ansi[15]).as_deref(), Some("#ebdbb2"));
+    assert_eq!(hex(&theme.ansi[0]), None);
+    Ok(())
+  }
+
+  #[test]
+  fn windows_terminal_json() -> anyhow::Result<()> {
+    let theme = structured(serde_json::from_str::<Value>(
+      r##"{
+  "schemes": [
+    {
+      "name": "Gruvbox",
+      "background": "#282828",
+      "foreground": "#EBDBB2",
+      "cursorColor": "#928374",
+      "purple": "#B16286",
+      "brightBlack": "#928374"
+    }
+  ]
+}"##,
+    )?)?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#282828"));
+    assert_eq!(hex(&theme.cursor).as_deref(), Some("#928374"));
+    assert_eq!(hex(&theme.ansi[5]).as_deref(), Some("#b16286"));
+    assert_eq!(hex(&theme.ansi[8]).as_deref(), Some("#928374"));
+    Ok(())
+  }
+
+  #[test]
+  fn windows_terminal_many_schemes() -> anyhow::Result<()> {
+    let theme = structured(serde_json::from_str::<Value>(
+      r##"{
+  "schemes": [
+    { "name": "Gruvbox", "background": "#282828" },
+    { "name": "Nord", "background": "#2E3440" }
+  ]
+}"##,
+    )?);
+    assert!(theme.is_err());
+    Ok(())
+  }
+
+  #[test]
+  fn iterm_plist() -> anyhow::Result<()> {
+    let theme = structured(plist::from_bytes::<Value>(
+      br#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>Ansi 1 Color</key>
+  <dict>
+    <key>Red Component</key>
+    <real>1</real>
+    <key>Green Component</key>
+    <real>0</real>
+    <key>Blue Component</key>
+    <real>0.5</real>
+  </dict>
+  <key>Background Color</key>
+  <dict>
+    <key>Red Component</key>
+    <real>0</real>
+    <key>Green Component</key>
+    <real>0</real>
+    <key>Blue Component</key>
+    <real>0</real>
+  </dict>
+</dict>
+</plist>"#,
+    )?)?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#000000"));
+    assert_eq!(hex(&theme.ansi[1]).as_deref(), Some("#ff0080"));
+    assert_eq!(hex(&theme.ansi[0]), None);
+    Ok(())
+  }
+
+  #[test]
+  fn kitty_lines() -> anyhow::Result<()> {
+    let theme = lines(
+      "# gruvbox\n\
+       background #282828\n\
+       foreground #ebdbb2\n\
+       cursor none\n\
+       color1 #cc241d\n\
+       color15 #ebdbb2\n",
+    )?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#282828"));
+    assert_eq!(hex(&theme.cursor), None);
+    assert_eq!(hex(&theme.ansi[1]).as_deref(), Some("#cc241d"));
+    assert_eq!(hex(&theme.ansi[15]).as_deref(), Some("#ebdbb2"));
+    Ok(())
+  }
+
+  #[test]
+  fn xresources_lines() -> anyhow::Result<()> {
+    let theme = lines(
+      "! gruvbox\n\
+       #define red #cc241d\n\
+       *.background: #282828\n\
+       *.cursorColor: #928374\n\
+       URxvt*color1: red\n\
+       *color9: #fb4934\n",
+    )?;
+    assert_eq!(hex(&theme.background).as_deref(), Some("#282828"));
+    assert_eq!(hex(&theme.cursor).as_deref(), Some("#928374"));
+    assert_eq!(hex(&theme.ansi[1]).as_deref(), Some("#cc241d"));
+    assert_eq!(hex(&theme.ansi[9]).as_deref(), Some("#fb4934"));
+    Ok(())
+  }
+}
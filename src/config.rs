@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -25,6 +27,15 @@ pub struct Config {
 
   #[serde(default)]
   pub ansi: AnsiConfig,
+
+  #[serde(default)]
+  pub semantic: SemanticConfig,
+
+  #[serde(default)]
+  pub tonal: TonalConfig,
+
+  #[serde(default)]
+  pub named: NamedConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -279,25 +290,68 @@ pub struct AnsiConfig {
 
   #[serde(default = "AnsiConfig::default_grayscale")]
   pub grayscale: AnsiAreaConfig,
+
+  // NOTE: only the factors are used and they scale the main colors
+  #[serde(default = "AnsiConfig::default_dim")]
+  pub dim: AnsiDimConfig,
+
+  #[serde(default)]
+  pub references: AnsiReferencesConfig,
+
+  #[serde(default)]
+  pub overrides: BTreeMap<AnsiSlot, AnsiOverrideConfig>,
+
+  #[serde(default)]
+  pub assignment: AnsiAssignmentConfig,
+
+  #[serde(default = "AnsiConfig::default_reuse_penalty")]
+  pub reuse_penalty: f32,
+
+  #[serde(default)]
+  pub contrast: Option<AnsiContrastConfig>,
+
+  #[serde(default)]
+  pub cvd: Option<AnsiCvdConfig>,
 }
 
 impl AnsiConfig {
+  fn default_reuse_penalty() -> f32 {
+    0.1
+  }
   fn default_main() -> AnsiAreaConfig {
     AnsiAreaConfig {
       lightness_factor: 0.6,
       saturation_factor: 0.6,
+      hue_factor: 0.0,
+      space: AnsiSpaceConfig::default(),
+      metric: AnsiMetricConfig::default(),
+      hue_weight: AnsiAreaConfig::default_hue_weight(),
     }
   }
   fn default_gradient() -> AnsiAreaConfig {
     AnsiAreaConfig {
       lightness_factor: 0.2,
       saturation_factor: 0.8,
+      hue_factor: 0.0,
+      space: AnsiSpaceConfig::default(),
+      metric: AnsiMetricConfig::default(),
+      hue_weight: AnsiAreaConfig::default_hue_weight(),
     }
   }
   fn default_grayscale() -> AnsiAreaConfig {
     AnsiAreaConfig {
       lightness_factor: 0.8,
       saturation_factor: 0.2,
+      hue_factor: 0.0,
+      space: AnsiSpaceConfig::default(),
+      metric: AnsiMetricConfig::default(),
+      hue_weight: AnsiAreaConfig::default_hue_weight(),
+    }
+  }
+  fn default_dim() -> AnsiDimConfig {
+    AnsiDimConfig {
+      lightness_factor: 0.66,
+      saturation_factor: 0.8,
     }
   }
 }
@@ -308,6 +362,13 @@ impl Default for AnsiConfig {
       main: Self::default_main(),
       gradient: Self::default_gradient(),
       grayscale: Self::default_grayscale(),
+      dim: Self::default_dim(),
+      references: AnsiReferencesConfig::default(),
+      overrides: BTreeMap::new(),
+      assignment: AnsiAssignmentConfig::default(),
+      reuse_penalty: Self::default_reuse_penalty(),
+      contrast: None,
+      cvd: None,
     }
   }
 }
@@ -317,6 +378,342 @@ pub struct AnsiAreaConfig {
   pub lightness_factor: f32,
 
   pub saturation_factor: f32,
+
+  #[serde(default)]
+  pub hue_factor: f32,
+
+  #[serde(default)]
+  pub space: AnsiSpaceConfig,
+
+  #[serde(default)]
+  pub metric: AnsiMetricConfig,
+
+  #[serde(default = "AnsiAreaConfig::default_hue_weight")]
+  pub hue_weight: f32,
+}
+
+impl AnsiAreaConfig {
+  fn default_hue_weight() -> f32 {
+    2.0
+  }
+}
+
+// NOTE: dim colors scale main colors so there is nothing to mix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnsiDimConfig {
+  pub lightness_factor: f32,
+
+  pub saturation_factor: f32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum AnsiSpaceConfig {
+  #[default]
+  Okhsl,
+  Okhsv,
+  Oklch,
+  Hsluv,
+  Lab,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum AnsiMetricConfig {
+  #[default]
+  Hyab,
+  Ciede2000,
+  Cie76,
+  Oklab,
+  HueWeighted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnsiContrastConfig {
+  #[serde(default)]
+  pub algorithm: AnsiContrastAlgorithmConfig,
+
+  // NOTE: defaults to the algorithm default minimum
+  #[serde(default)]
+  pub minimum: Option<f32>,
+
+  #[serde(default = "AnsiContrastConfig::default_background")]
+  pub background: AnsiSlot,
+
+  #[serde(default = "AnsiContrastConfig::default_foreground")]
+  pub foreground: AnsiSlot,
+}
+
+impl AnsiContrastConfig {
+  fn default_background() -> AnsiSlot {
+    AnsiSlot::Black
+  }
+
+  fn default_foreground() -> AnsiSlot {
+    AnsiSlot::White
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnsiCvdConfig {
+  pub deficiency: CvdDeficiencyConfig,
+
+  #[serde(default = "AnsiCvdConfig::default_threshold")]
+  pub threshold: f32,
+}
+
+impl AnsiCvdConfig {
+  fn default_threshold() -> f32 {
+    0.1
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CvdDeficiencyConfig {
+  Protan,
+  Deutan,
+  Tritan,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum AnsiContrastAlgorithmConfig {
+  #[default]
+  Wcag,
+  Apca,
+}
+
+impl AnsiContrastAlgorithmConfig {
+  pub fn default_minimum(&self) -> f32 {
+    match self {
+      AnsiContrastAlgorithmConfig::Wcag => 4.5, // NOTE: WCAG AA for normal text
+      AnsiContrastAlgorithmConfig::Apca => 60.0, // NOTE: APCA Lc for body text
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum AnsiAssignmentConfig {
+  #[default]
+  Independent,
+  Unique,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnsiReferencesConfig {
+  #[serde(default)]
+  pub preset: AnsiReferencesPreset,
+
+  #[serde(default, flatten)]
+  pub colors: BTreeMap<AnsiSlot, ColorConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum AnsiReferencesPreset {
+  #[default]
+  Default,
+  Xterm,
+  Vga,
+  Tango,
+  Solarized,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnsiOverrideConfig {
+  #[serde(default)]
+  pub color: Option<ColorConfig>,
+
+  #[serde(default)]
+  pub min_saturation: Option<f32>,
+
+  #[serde(default)]
+  pub hue: Option<(f32, f32)>,
+
+  #[serde(default)]
+  pub lightness: Option<(f32, f32)>,
+}
+
+#[derive(
+  Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AnsiSlot {
+  Black,
+  Red,
+  Green,
+  Blue,
+  Cyan,
+  Yellow,
+  Magenta,
+  White,
+  BrightBlack,
+  BrightRed,
+  BrightGreen,
+  BrightBlue,
+  BrightCyan,
+  BrightYellow,
+  BrightMagenta,
+  BrightWhite,
+}
+
+impl std::str::FromStr for AnsiSlot {
+  type Err = anyhow::Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Ok(Self::deserialize(serde::de::IntoDeserializer::<
+      serde::de::value::Error,
+    >::into_deserializer(string))?)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedConfig {
+  #[serde(default)]
+  pub colors: BTreeMap<String, ColorConfig>,
+
+  #[serde(default = "NamedConfig::default_mix")]
+  pub mix: AnsiAreaConfig,
+}
+
+impl NamedConfig {
+  fn default_mix() -> AnsiAreaConfig {
+    AnsiConfig::default_main()
+  }
+}
+
+impl Default for NamedConfig {
+  fn default() -> Self {
+    Self {
+      colors: BTreeMap::new(),
+      mix: Self::default_mix(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TonalConfig {
+  // NOTE: defaults to the most prominent colorful image color
+  #[serde(default)]
+  pub seed: Option<ColorConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SemanticConfig {
+  #[serde(default, flatten)]
+  pub overrides: BTreeMap<SemanticRole, SemanticOverrideConfig>,
+}
+
+#[derive(
+  Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SemanticRole {
+  Background,
+  Foreground,
+  Cursor,
+  CursorText,
+  SelectionBackground,
+  SelectionForeground,
+  Url,
+  Accent,
+  Surface,
+  Border,
+  Muted,
+  Error,
+  Warning,
+  Success,
+  Info,
+}
+
+// NOTE: an ANSI color name like "bright_blue" or a color
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SemanticOverrideConfig {
+  Slot(AnsiSlot),
+  Color(ColorConfig),
+}
+
+// NOTE: "#rgb", "#rrggbb" or "oklch(lightness chroma hue)"
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ColorConfig {
+  Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+  },
+  Oklch {
+    lightness: f32,
+    chroma: f32,
+    hue: f32,
+  },
+}
+
+impl std::str::FromStr for ColorConfig {
+  type Err = anyhow::Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    let string = string.trim();
+
+    if string.starts_with('#') {
+      let crate::colors::Rgba {
+        red, green, blue, ..
+      } = crate::colors::hex(string)?;
+      return Ok(Self::Rgb { red, green, blue });
+    }
+
+    if let Some(components) = string
+      .strip_prefix("oklch(")
+      .and_then(|string| string.strip_suffix(')'))
+    {
+      let components = components
+        .split(|character: char| character == ',' || character.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<_>>();
+      let (lightness, chroma, hue) = match components.as_slice() {
+        [lightness, chroma, hue] => (
+          match lightness.strip_suffix('%') {
+            Some(percentage) => percentage.parse::<f32>()? / 100.0f32,
+            None => lightness.parse::<f32>()?,
+          },
+          chroma.parse::<f32>()?,
+          hue.trim_end_matches("deg").parse::<f32>()?,
+        ),
+        _ => return Err(anyhow::anyhow!("Invalid oklch color {string}")),
+      };
+      if !lightness.is_finite() || !chroma.is_finite() || !hue.is_finite() {
+        return Err(anyhow::anyhow!("Invalid oklch color {string}"));
+      }
+
+      return Ok(Self::Oklch {
+        lightness,
+        chroma,
+        hue,
+      });
+    }
+
+    Err(anyhow::anyhow!("Invalid color {string}"))
+  }
+}
+
+impl TryFrom<String> for ColorConfig {
+  type Error = anyhow::Error;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<ColorConfig> for String {
+  fn from(value: ColorConfig) -> Self {
+    match value {
+      ColorConfig::Rgb { red, green, blue } => {
+        format!("#{red:02x}{green:02x}{blue:02x}")
+      }
+      ColorConfig::Oklch {
+        lightness,
+        chroma,
+        hue,
+      } => format!("oklch({lightness} {chroma} {hue})"),
+    }
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -2,44 +2,337 @@ use colored::Colorize;
 use lazy_static::lazy_static;
 use palette::IntoColor;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Config {
   pub main: AreaConfig,
   pub gradient: AreaConfig,
   pub grayscale: AreaConfig,
+  pub dim: DimConfig,
+  pub references: ResultMain<Reference>,
+  pub overrides: ResultMain<Override>,
+  pub assignment: Assignment,
+  pub contrast: Option<Contrast>,
+  pub cvd: Option<Cvd>,
+  pub mode: Mode,
+}
+
+// NOTE: threshold is the Oklab distance simulated colors should keep
+#[derive(Debug, Clone, Copy)]
+pub struct Cvd {
+  pub deficiency: super::cvd::Deficiency,
+  pub threshold: f32,
+}
+
+// NOTE: light inverts main reference lightness so black becomes the light one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+  Dark,
+  Light,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Contrast {
+  pub algorithm: ContrastAlgorithm,
+  pub minimum: f32,
+  pub background: Slot,
+  pub foreground: Slot,
+}
+
+// NOTE: WCAG minimum is a ratio like 4.5 and APCA minimum a lightness like 60
+#[derive(Debug, Clone, Copy)]
+pub enum ContrastAlgorithm {
+  Wcag,
+  Apca,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Assignment {
+  // NOTE: every main color picks its closest palette color on its own
+  Independent,
+  // NOTE: main colors pick distinct palette colors while the palette allows it
+  Unique { reuse_penalty: f32 },
+}
+
+// NOTE: applied after mixing - pin replaces the color and the rest constrain it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Override {
+  pub pin: Option<Reference>,
+  pub min_saturation: Option<f32>,
+  pub hue: Option<(f32, f32)>,
+  pub lightness: Option<(f32, f32)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Reference {
+  Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+  },
+  Oklch {
+    lightness: f32,
+    chroma: f32,
+    hue: f32,
+  },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Preset {
+  Default,
+  Xterm,
+  Vga,
+  Tango,
+  Solarized,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+  Black,
+  Red,
+  Green,
+  Blue,
+  Cyan,
+  Yellow,
+  Magenta,
+  White,
+  BrightBlack,
+  BrightRed,
+  BrightGreen,
+  BrightBlue,
+  BrightCyan,
+  BrightYellow,
+  BrightMagenta,
+  BrightWhite,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DimConfig {
+  pub saturation_factor: f32,
+  pub lightness_factor: f32,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct AreaConfig {
   pub saturation_factor: f32,
   pub lightness_factor: f32,
+  pub hue_factor: f32,
+  pub space: Space,
+  pub metric: Metric,
+}
+
+// NOTE: Lab has no hue so there saturation factor moves a and b instead
+#[derive(Debug, Clone, Copy)]
+pub enum Space {
+  Okhsl,
+  Okhsv,
+  Oklch,
+  Hsluv,
+  Lab,
+}
+
+// NOTE: CIE metrics are scaled down by 100 to be comparable to Oklab ones
+#[derive(Debug, Clone, Copy)]
+pub enum Metric {
+  Hyab,
+  Ciede2000,
+  Cie76,
+  Oklab,
+  HueWeighted { hue_weight: f32 },
 }
 
 #[derive(Debug, Clone)]
 pub struct Result {
   pub main: ResultMain,
+  pub dim: ResultMain,
   pub gradient: Vec<Rgba>,
   pub grayscale: Vec<Rgba>,
+
+  // NOTE: index of the palette color each main color was mixed from
+  // NOTE: none for pinned main colors because their pick is not used
+  pub picks: ResultMain<Option<usize>>,
+  pub pinned: ResultMain<bool>,
 }
 
-#[derive(Debug, Clone)]
-pub struct ResultMain {
-  pub black: Rgba,
-  pub red: Rgba,
-  pub green: Rgba,
-  pub blue: Rgba,
-  pub cyan: Rgba,
-  pub yellow: Rgba,
-  pub magenta: Rgba,
-  pub white: Rgba,
-  pub bright_black: Rgba,
-  pub bright_red: Rgba,
-  pub bright_green: Rgba,
-  pub bright_blue: Rgba,
-  pub bright_cyan: Rgba,
-  pub bright_yellow: Rgba,
-  pub bright_magenta: Rgba,
-  pub bright_white: Rgba,
+#[derive(Debug, Clone, Default)]
+pub struct ResultMain<T = Rgba> {
+  pub black: T,
+  pub red: T,
+  pub green: T,
+  pub blue: T,
+  pub cyan: T,
+  pub yellow: T,
+  pub magenta: T,
+  pub white: T,
+  pub bright_black: T,
+  pub bright_red: T,
+  pub bright_green: T,
+  pub bright_blue: T,
+  pub bright_cyan: T,
+  pub bright_yellow: T,
+  pub bright_magenta: T,
+  pub bright_white: T,
+}
+
+impl Result {
+  // NOTE: xterm 256 color palette order
+  pub fn indexed(&self) -> Vec<Rgba> {
+    let ResultMain {
+      black,
+      red,
+      green,
+      blue,
+      cyan,
+      yellow,
+      magenta,
+      white,
+      bright_black,
+      bright_red,
+      bright_green,
+      bright_blue,
+      bright_cyan,
+      bright_yellow,
+      bright_magenta,
+      bright_white,
+    } = &self.main;
+
+    [
+      black,
+      red,
+      green,
+      yellow,
+      blue,
+      magenta,
+      cyan,
+      white,
+      bright_black,
+      bright_red,
+      bright_green,
+      bright_yellow,
+      bright_blue,
+      bright_magenta,
+      bright_cyan,
+      bright_white,
+    ]
+    .into_iter()
+    .chain(self.gradient.iter())
+    .chain(self.grayscale.iter())
+    .cloned()
+    .collect()
+  }
+}
+
+impl Slot {
+  pub const ALL: [Slot; 16] = [
+    Slot::Black,
+    Slot::Red,
+    Slot::Green,
+    Slot::Blue,
+    Slot::Cyan,
+    Slot::Yellow,
+    Slot::Magenta,
+    Slot::White,
+    Slot::BrightBlack,
+    Slot::BrightRed,
+    Slot::BrightGreen,
+    Slot::BrightBlue,
+    Slot::BrightCyan,
+    Slot::BrightYellow,
+    Slot::BrightMagenta,
+    Slot::BrightWhite,
+  ];
+}
+
+impl<T> ResultMain<T> {
+  pub fn get(&self, slot: Slot) -> &T {
+    match slot {
+      Slot::Black => &self.black,
+      Slot::Red => &self.red,
+      Slot::Green => &self.green,
+      Slot::Blue => &self.blue,
+      Slot::Cyan => &self.cyan,
+      Slot::Yellow => &self.yellow,
+      Slot::Magenta => &self.magenta,
+      Slot::White => &self.white,
+      Slot::BrightBlack => &self.bright_black,
+      Slot::BrightRed => &self.bright_red,
+      Slot::BrightGreen => &self.bright_green,
+      Slot::BrightBlue => &self.bright_blue,
+      Slot::BrightCyan => &self.bright_cyan,
+      Slot::BrightYellow => &self.bright_yellow,
+      Slot::BrightMagenta => &self.bright_magenta,
+      Slot::BrightWhite => &self.bright_white,
+    }
+  }
+
+  pub fn get_mut(&mut self, slot: Slot) -> &mut T {
+    match slot {
+      Slot::Black => &mut self.black,
+      Slot::Red => &mut self.red,
+      Slot::Green => &mut self.green,
+      Slot::Blue => &mut self.blue,
+      Slot::Cyan => &mut self.cyan,
+      Slot::Yellow => &mut self.yellow,
+      Slot::Magenta => &mut self.magenta,
+      Slot::White => &mut self.white,
+      Slot::BrightBlack => &mut self.bright_black,
+      Slot::BrightRed => &mut self.bright_red,
+      Slot::BrightGreen => &mut self.bright_green,
+      Slot::BrightBlue => &mut self.bright_blue,
+      Slot::BrightCyan => &mut self.bright_cyan,
+      Slot::BrightYellow => &mut self.bright_yellow,
+      Slot::BrightMagenta => &mut self.bright_magenta,
+      Slot::BrightWhite => &mut self.bright_white,
+    }
+  }
+}
+
+impl<T> ResultMain<T> {
+  pub fn into_array(self) -> [T; 16] {
+    [
+      self.black,
+      self.red,
+      self.green,
+      self.blue,
+      self.cyan,
+      self.yellow,
+      self.magenta,
+      self.white,
+      self.bright_black,
+      self.bright_red,
+      self.bright_green,
+      self.bright_blue,
+      self.bright_cyan,
+      self.bright_yellow,
+      self.bright_magenta,
+      self.bright_white,
+    ]
+  }
+}
+
+impl<T> From<[T; 16]> for ResultMain<T> {
+  fn from(main: [T; 16]) -> Self {
+    let [black, red, green, blue, cyan, yellow, magenta, white, bright_black, bright_red, bright_green, bright_blue, bright_cyan, bright_yellow, bright_magenta, bright_white] =
+      main;
+
+    Self {
+      black,
+      red,
+      green,
+      blue,
+      cyan,
+      yellow,
+      magenta,
+      white,
+      bright_black,
+      bright_red,
+      bright_green,
+      bright_blue,
+      bright_cyan,
+      bright_yellow,
+      bright_magenta,
+      bright_white,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +345,12 @@ pub struct Rgba {
 
 type Color = palette::Oklaba<f32>;
 type Hsla = palette::Okhsla<f32>;
+type Hsva = palette::Okhsva<f32>;
+type Lch = palette::Oklch<f32>;
+type Lcha = palette::Oklcha<f32>;
+type Hsluva = palette::Hsluva<palette::white_point::D65, f32>;
+type Lab = palette::Lab<palette::white_point::D65, f32>;
+type Laba = palette::Laba<palette::white_point::D65, f32>;
 type ContinuonsRgb = palette::Srgb<f32>;
 type ContinuousRgba = palette::Alpha<ContinuonsRgb, f32>;
 type DiscreteRgb = palette::LinSrgb<u8>;
@@ -60,82 +359,242 @@ type DiscreteRgba = palette::Alpha<DiscreteRgb, f32>;
 lazy_static! {
   static ref EMPTY: u8 = 0;
   static ref FULL: u8 = 255;
-  static ref PART32: u8 = (Into::<f32>::into(*FULL) / 32.0f32).floor() as u8;
-  static ref SIXTH: u8 = (Into::<f32>::into(*FULL) / 6.0f32).floor() as u8;
   static ref THIRD: u8 = (Into::<f32>::into(*FULL) / 3.0f32).floor() as u8;
   static ref HALF: u8 = (Into::<f32>::into(*FULL) / 2.0f32).floor() as u8;
 }
 
-lazy_static! {
-  static ref BLACK: Color = opaque(*EMPTY, *EMPTY, *EMPTY);
-  static ref RED: Color = opaque(*HALF, *EMPTY, *EMPTY);
-  static ref GREEN: Color = opaque(*EMPTY, *HALF, *EMPTY);
-  static ref BLUE: Color = opaque(*EMPTY, *EMPTY, *HALF);
-  static ref CYAN: Color = opaque(*EMPTY, *HALF, *HALF);
-  static ref YELLOW: Color = opaque(*HALF, *HALF, *EMPTY);
-  static ref MAGENTA: Color = opaque(*HALF, *EMPTY, *HALF);
-  static ref BRIGHT_GREY: Color = opaque(2 * *THIRD, 2 * *THIRD, 2 * *THIRD);
-  static ref GREY: Color = opaque(*THIRD, *THIRD, *THIRD);
-  static ref BRIGHT_RED: Color = opaque(*FULL, *EMPTY, *EMPTY);
-  static ref BRIGHT_GREEN: Color = opaque(*EMPTY, *FULL, *EMPTY);
-  static ref BRIGHT_BLUE: Color = opaque(*EMPTY, *EMPTY, *FULL);
-  static ref BRIGHT_CYAN: Color = opaque(*EMPTY, *FULL, *FULL);
-  static ref BRIGHT_YELLOW: Color = opaque(*FULL, *FULL, *EMPTY);
-  static ref BRIGHT_MAGENTA: Color = opaque(*FULL, *EMPTY, *FULL);
-  static ref WHITE: Color = opaque(*FULL, *FULL, *FULL);
-}
+// NOTE: xterm 256 color cube levels and grayscale ramp
+
+pub const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// NOTE: xterm default 16 colors in xterm order
+pub const XTERM: [(u8, u8, u8); 16] = [
+  (0, 0, 0),
+  (205, 0, 0),
+  (0, 205, 0),
+  (205, 205, 0),
+  (0, 0, 238),
+  (205, 0, 205),
+  (0, 205, 205),
+  (229, 229, 229),
+  (127, 127, 127),
+  (255, 0, 0),
+  (0, 255, 0),
+  (255, 255, 0),
+  (92, 92, 255),
+  (255, 0, 255),
+  (0, 255, 255),
+  (255, 255, 255),
+];
+
+// NOTE: colors below this chroma are too gray to stand out
+const MIN_COLORFUL_CHROMA: f32 = 0.04f32;
 
 lazy_static! {
-  static ref GRADIENT: Vec<Color> = (0..6)
-    .flat_map(move |r| (0..6).map(move |g| (0..6).map(move |b| opaque(
-      r * *SIXTH,
-      g * *SIXTH,
-      b * *SIXTH
-    ))))
+  static ref GRADIENT: Vec<Color> = CUBE
+    .iter()
+    .flat_map(move |r| CUBE
+      .iter()
+      .map(move |g| CUBE.iter().map(move |b| opaque(*r, *g, *b))))
     .flatten()
     .collect();
 }
 
 lazy_static! {
-  static ref GRAYSCALE: Vec<Color> = (0..32)
-    .map(|i| opaque(i * *PART32, i * *PART32, i * *PART32))
+  static ref GRAYSCALE: Vec<Color> = (0..24)
+    .map(|i| opaque(8 + i * 10, 8 + i * 10, 8 + i * 10))
     .collect();
 }
 
+pub fn references(preset: Preset) -> ResultMain<Reference> {
+  let rgb = |red, green, blue| Reference::Rgb { red, green, blue };
+
+  ResultMain::from(match preset {
+    Preset::Default => [
+      rgb(*EMPTY, *EMPTY, *EMPTY),
+      rgb(*HALF, *EMPTY, *EMPTY),
+      rgb(*EMPTY, *HALF, *EMPTY),
+      rgb(*EMPTY, *EMPTY, *HALF),
+      rgb(*EMPTY, *HALF, *HALF),
+      rgb(*HALF, *HALF, *EMPTY),
+      rgb(*HALF, *EMPTY, *HALF),
+      rgb(*THIRD, *THIRD, *THIRD),
+      rgb(2 * *THIRD, 2 * *THIRD, 2 * *THIRD),
+      rgb(*FULL, *EMPTY, *EMPTY),
+      rgb(*EMPTY, *FULL, *EMPTY),
+      rgb(*EMPTY, *EMPTY, *FULL),
+      rgb(*EMPTY, *FULL, *FULL),
+      rgb(*FULL, *FULL, *EMPTY),
+      rgb(*FULL, *EMPTY, *FULL),
+      rgb(*FULL, *FULL, *FULL),
+    ],
+    // NOTE: slots are not in xterm order
+    Preset::Xterm => [0, 1, 2, 4, 6, 3, 5, 7, 8, 9, 10, 12, 14, 11, 13, 15]
+      .map(|index| {
+        let (red, green, blue) = XTERM[index];
+        rgb(red, green, blue)
+      }),
+    Preset::Vga => [
+      rgb(0, 0, 0),
+      rgb(170, 0, 0),
+      rgb(0, 170, 0),
+      rgb(0, 0, 170),
+      rgb(0, 170, 170),
+      rgb(170, 85, 0),
+      rgb(170, 0, 170),
+      rgb(170, 170, 170),
+      rgb(85, 85, 85),
+      rgb(255, 85, 85),
+      rgb(85, 255, 85),
+      rgb(85, 85, 255),
+      rgb(85, 255, 255),
+      rgb(255, 255, 85),
+      rgb(255, 85, 255),
+      rgb(255, 255, 255),
+    ],
+    Preset::Tango => [
+      rgb(46, 52, 54),
+      rgb(204, 0, 0),
+      rgb(78, 154, 6),
+      rgb(52, 101, 164),
+      rgb(6, 152, 154),
+      rgb(196, 160, 0),
+      rgb(117, 80, 123),
+      rgb(211, 215, 207),
+      rgb(85, 87, 83),
+      rgb(239, 41, 41),
+      rgb(138, 226, 52),
+      rgb(114, 159, 207),
+      rgb(52, 226, 226),
+      rgb(252, 233, 79),
+      rgb(173, 127, 168),
+      rgb(238, 238, 236),
+    ],
+    // NOTE: solarized puts its base tones into the bright colors
+    Preset::Solarized => [
+      rgb(7, 54, 66),
+      rgb(220, 50, 47),
+      rgb(133, 153, 0),
+      rgb(38, 139, 210),
+      rgb(42, 161, 152),
+      rgb(181, 137, 0),
+      rgb(211, 54, 130),
+      rgb(238, 232, 213),
+      rgb(0, 43, 54),
+      rgb(203, 75, 22),
+      rgb(88, 110, 117),
+      rgb(131, 148, 150),
+      rgb(147, 161, 161),
+      rgb(101, 123, 131),
+      rgb(108, 113, 196),
+      rgb(253, 246, 227),
+    ],
+  })
+}
+
+// NOTE: light when the weighted average luminance of the displayed colors
+// NOTE: is above 0.179 where black and white text contrast the same
+pub fn mode(palette: &[Rgba], weights: &[f32]) -> Mode {
+  let total = weights.iter().sum::<f32>();
+  if total <= 0.0f32 {
+    return Mode::Dark;
+  }
+
+  let luminance = palette
+    .iter()
+    .zip(weights.iter())
+    .map(|(color, weight)| {
+      super::contrast::luminance((color.red, color.green, color.blue)) * weight
+    })
+    .sum::<f32>()
+    / total;
+  tracing::debug!("Average image luminance {}", luminance);
+
+  if luminance > 0.179f32 {
+    Mode::Light
+  } else {
+    Mode::Dark
+  }
+}
+
 pub fn from(palette: Vec<Rgba>, config: Config) -> Result {
   let palette = from_rgba(&palette);
 
+  let references = Slot::ALL.map(|slot| {
+    let mut reference = from_reference(*config.references.get(slot));
+    if config.mode == Mode::Light {
+      reference.l = 1.0f32 - reference.l;
+    }
+    reference
+  });
+  let picks = match config.assignment {
+    Assignment::Independent => references
+      .map(|reference| closest_to(&palette, reference, config.main.metric)),
+    Assignment::Unique { reuse_penalty } => {
+      assign(&palette, &references, reuse_penalty, config.main.metric)
+    }
+  };
+
+  let mut main = ResultMain::from(std::array::from_fn(|index| {
+    let mixed =
+      mix_pick(&palette, picks[index], references[index], config.main);
+    let r#override = *config.overrides.get(Slot::ALL[index]);
+    constrain(mixed, r#override)
+  }));
+
+  if let Some(cvd) = config.cvd {
+    let pinned =
+      |slot: Slot| -> bool { config.overrides.get(slot).pin.is_some() };
+    separate(&mut main, cvd, pinned);
+  }
+
+  if let Some(contrast) = config.contrast {
+    let pinned =
+      |slot: Slot| -> bool { config.overrides.get(slot).pin.is_some() };
+    if !pinned(contrast.background) {
+      let foreground = *main.get(contrast.foreground);
+      let background = main.get_mut(contrast.background);
+      *background =
+        ensure_contrast(contrast.background, *background, foreground, contrast);
+    }
+
+    let background = *main.get(contrast.background);
+    for slot in Slot::ALL {
+      if slot == contrast.background || pinned(slot) {
+        continue;
+      }
+      let color = main.get_mut(slot);
+      *color = ensure_contrast(slot, *color, background, contrast);
+    }
+  }
+
+  let main = main.into_array();
   Result {
-    main: ResultMain {
-      black: mix_closest_to(&palette, *BLACK, config.main),
-      red: mix_closest_to(&palette, *RED, config.main),
-      green: mix_closest_to(&palette, *GREEN, config.main),
-      blue: mix_closest_to(&palette, *BLUE, config.main),
-      cyan: mix_closest_to(&palette, *CYAN, config.main),
-      yellow: mix_closest_to(&palette, *YELLOW, config.main),
-      magenta: mix_closest_to(&palette, *MAGENTA, config.main),
-      white: mix_closest_to(&palette, *GREY, config.main),
-      bright_black: mix_closest_to(&palette, *BRIGHT_GREY, config.main),
-      bright_red: mix_closest_to(&palette, *BRIGHT_RED, config.main),
-      bright_green: mix_closest_to(&palette, *BRIGHT_GREEN, config.main),
-      bright_blue: mix_closest_to(&palette, *BRIGHT_BLUE, config.main),
-      bright_cyan: mix_closest_to(&palette, *BRIGHT_CYAN, config.main),
-      bright_yellow: mix_closest_to(&palette, *BRIGHT_YELLOW, config.main),
-      bright_magenta: mix_closest_to(&palette, *BRIGHT_MAGENTA, config.main),
-      bright_white: mix_closest_to(&palette, *WHITE, config.main),
-    },
+    dim: ResultMain::from(
+      main.map(|color| to_rgba(dim(color, config.dim, config.mode))),
+    ),
+    main: ResultMain::from(main.map(to_rgba)),
     gradient: (*GRADIENT)
       .iter()
-      .map(|color| mix_closest_to(&palette, *color, config.gradient))
+      .map(|color| to_rgba(mix_closest_to(&palette, *color, config.gradient).1))
       .collect(),
     grayscale: (*GRAYSCALE)
       .iter()
-      .map(|color| mix_closest_to(&palette, *color, config.grayscale))
+      .map(|color| {
+        to_rgba(mix_closest_to(&palette, *color, config.grayscale).1)
+      })
       .collect(),
+    picks: ResultMain::from(std::array::from_fn(|index| {
+      picks[index]
+        .filter(|_| config.overrides.get(Slot::ALL[index]).pin.is_none())
+    })),
+    pinned: ResultMain::from(
+      Slot::ALL.map(|slot| config.overrides.get(slot).pin.is_some()),
+    ),
   }
 }
 
-fn from_rgba(palette: &[Rgba]) -> Vec<Color> {
+pub(super) fn from_rgba(palette: &[Rgba]) -> Vec<Color> {
   palette
     .iter()
     .map(
@@ -153,7 +612,18 @@ fn from_rgba(palette: &[Rgba]) -> Vec<Color> {
     .collect()
 }
 
-fn to_rgba(color: Color) -> Rgba {
+pub(super) fn from_reference(reference: Reference) -> Color {
+  match reference {
+    Reference::Rgb { red, green, blue } => opaque(red, green, blue),
+    Reference::Oklch {
+      lightness,
+      chroma,
+      hue,
+    } => palette::Oklcha::new(lightness, chroma, hue, 1.0f32).into_color(),
+  }
+}
+
+pub(super) fn to_rgba(color: Color) -> Rgba {
   let DiscreteRgba {
     color: DiscreteRgb {
       red, green, blue, ..
@@ -170,45 +640,412 @@ fn to_rgba(color: Color) -> Rgba {
   }
 }
 
-fn mix_closest_to(palette: &[Color], color: Color, config: AreaConfig) -> Rgba {
-  let closest = closest_to(palette, color).unwrap_or_default();
+// NOTE: the most prominent colorful palette color by chroma times weight
+pub(super) fn most_colorful(palette: &[Rgba], weights: &[f32]) -> Option<Lcha> {
+  from_rgba(palette)
+    .into_iter()
+    .zip(weights.iter())
+    .map(|(color, weight)| (IntoColor::<Lcha>::into_color(color), *weight))
+    .filter(|(color, _)| color.chroma >= MIN_COLORFUL_CHROMA)
+    .max_by(|(lhs, lhs_weight), (rhs, rhs_weight)| {
+      (lhs.chroma * lhs_weight).total_cmp(&(rhs.chroma * rhs_weight))
+    })
+    .map(|(color, _)| color)
+}
+
+pub(super) fn mix_closest_to(
+  palette: &[Color],
+  color: Color,
+  config: AreaConfig,
+) -> (Option<usize>, Color) {
+  let pick = closest_to(palette, color, config.metric);
+  (pick, mix_pick(palette, pick, color, config))
+}
+
+fn mix_pick(
+  palette: &[Color],
+  pick: Option<usize>,
+  color: Color,
+  config: AreaConfig,
+) -> Color {
+  let closest = pick
+    .and_then(|index| palette.get(index))
+    .cloned()
+    .unwrap_or_default();
   let mixed = mix(closest, color, config);
-  let result = to_rgba(mixed);
 
   #[cfg(debug_assertions)]
   {
     print(color, closest, mixed, config);
   }
 
-  result
+  mixed
 }
 
-fn closest_to(palette: &[Color], reference: Color) -> Option<Color> {
+fn closest_to(
+  palette: &[Color],
+  reference: Color,
+  metric: Metric,
+) -> Option<usize> {
   palette
     .iter()
-    .min_by(|x, y| {
-      let dist_x = palette::color_difference::HyAb::hybrid_distance(
-        x.color,
-        reference.color,
-      );
-      let dist_y = palette::color_difference::HyAb::hybrid_distance(
-        y.color,
-        reference.color,
-      );
-      dist_x.total_cmp(&dist_y)
+    .enumerate()
+    .min_by(|(_, x), (_, y)| {
+      distance(**x, reference, metric)
+        .total_cmp(&distance(**y, reference, metric))
     })
-    .cloned()
+    .map(|(index, _)| index)
+}
+
+// NOTE: palette colors get repeated with an increasing penalty
+// NOTE: so that there's always enough of them for all references
+fn assign(
+  palette: &[Color],
+  references: &[Color; 16],
+  reuse_penalty: f32,
+  metric: Metric,
+) -> [Option<usize>; 16] {
+  if palette.is_empty() {
+    return [None; 16];
+  }
+
+  let repeats = references.len().div_ceil(palette.len());
+  let costs = references
+    .iter()
+    .map(|reference| {
+      (0..repeats)
+        .flat_map(|repeat| {
+          palette.iter().map(move |color| {
+            distance(*color, *reference, metric) + repeat as f32 * reuse_penalty
+          })
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  let assignment = super::hungarian::solve(&costs);
+  std::array::from_fn(|index| {
+    assignment
+      .get(index)
+      .copied()
+      .flatten()
+      .map(|column| column % palette.len())
+  })
+}
+
+fn distance(lhs: Color, rhs: Color, metric: Metric) -> f32 {
+  match metric {
+    Metric::Hyab => {
+      palette::color_difference::HyAb::hybrid_distance(lhs.color, rhs.color)
+    }
+    Metric::Ciede2000 => {
+      palette::color_difference::Ciede2000::difference(
+        IntoColor::<Lab>::into_color(lhs.color),
+        IntoColor::<Lab>::into_color(rhs.color),
+      ) / 100.0f32
+    }
+    Metric::Cie76 => {
+      palette::color_difference::EuclideanDistance::distance(
+        IntoColor::<Lab>::into_color(lhs.color),
+        IntoColor::<Lab>::into_color(rhs.color),
+      ) / 100.0f32
+    }
+    Metric::Oklab => palette::color_difference::EuclideanDistance::distance(
+      lhs.color, rhs.color,
+    ),
+    Metric::HueWeighted { hue_weight } => {
+      let lhs = IntoColor::<Lch>::into_color(lhs.color);
+      let rhs = IntoColor::<Lch>::into_color(rhs.color);
+      let lightness = lhs.l - rhs.l;
+      let chroma = lhs.chroma - rhs.chroma;
+      let hue = 2.0f32
+        * (lhs.chroma * rhs.chroma).sqrt()
+        * ((lhs.hue - rhs.hue).into_radians() / 2.0f32).sin();
+
+      (lightness * lightness
+        + chroma * chroma
+        + hue_weight * hue_weight * hue * hue)
+        .sqrt()
+    }
+  }
 }
 
 fn mix(lhs: Color, rhs: Color, config: AreaConfig) -> Color {
-  let lhs_hsla = palette::IntoColor::<Hsla>::into_color(lhs);
-  let rhs_hsla = palette::IntoColor::<Hsla>::into_color(rhs);
-  let saturation = lhs_hsla.saturation
-    + (rhs_hsla.saturation - lhs_hsla.saturation) * config.saturation_factor;
-  let lightness = lhs_hsla.lightness
-    + (rhs_hsla.lightness - lhs_hsla.lightness) * config.lightness_factor;
+  let AreaConfig {
+    saturation_factor,
+    lightness_factor,
+    hue_factor,
+    ..
+  } = config;
+
+  match config.space {
+    Space::Okhsl => {
+      let lhs = IntoColor::<Hsla>::into_color(lhs);
+      let rhs = IntoColor::<Hsla>::into_color(rhs);
+      Hsla::new(
+        lerp_hue(lhs.hue.into_degrees(), rhs.hue.into_degrees(), hue_factor),
+        lerp(lhs.saturation, rhs.saturation, saturation_factor),
+        lerp(lhs.lightness, rhs.lightness, lightness_factor),
+        rhs.alpha,
+      )
+      .into_color()
+    }
+    Space::Okhsv => {
+      let lhs = IntoColor::<Hsva>::into_color(lhs);
+      let rhs = IntoColor::<Hsva>::into_color(rhs);
+      Hsva::new(
+        lerp_hue(lhs.hue.into_degrees(), rhs.hue.into_degrees(), hue_factor),
+        lerp(lhs.saturation, rhs.saturation, saturation_factor),
+        lerp(lhs.value, rhs.value, lightness_factor),
+        rhs.alpha,
+      )
+      .into_color()
+    }
+    Space::Oklch => {
+      let lhs = IntoColor::<Lcha>::into_color(lhs);
+      let rhs = IntoColor::<Lcha>::into_color(rhs);
+      Lcha::new(
+        lerp(lhs.l, rhs.l, lightness_factor),
+        lerp(lhs.chroma, rhs.chroma, saturation_factor),
+        lerp_hue(lhs.hue.into_degrees(), rhs.hue.into_degrees(), hue_factor),
+        rhs.alpha,
+      )
+      .into_color()
+    }
+    Space::Hsluv => {
+      let lhs = IntoColor::<Hsluva>::into_color(lhs);
+      let rhs = IntoColor::<Hsluva>::into_color(rhs);
+      Hsluva::new(
+        lerp_hue(lhs.hue.into_degrees(), rhs.hue.into_degrees(), hue_factor),
+        lerp(lhs.saturation, rhs.saturation, saturation_factor),
+        lerp(lhs.l, rhs.l, lightness_factor),
+        rhs.alpha,
+      )
+      .into_color()
+    }
+    Space::Lab => {
+      let lhs = IntoColor::<Laba>::into_color(lhs);
+      let rhs = IntoColor::<Laba>::into_color(rhs);
+      Laba::new(
+        lerp(lhs.l, rhs.l, lightness_factor),
+        lerp(lhs.a, rhs.a, saturation_factor),
+        lerp(lhs.b, rhs.b, saturation_factor),
+        rhs.alpha,
+      )
+      .into_color()
+    }
+  }
+}
+
+fn lerp(lhs: f32, rhs: f32, factor: f32) -> f32 {
+  lhs + (rhs - lhs) * factor
+}
+
+// NOTE: goes the shorter way around the color wheel
+pub(super) fn lerp_hue(lhs: f32, rhs: f32, factor: f32) -> f32 {
+  let delta = (rhs - lhs + 540.0f32).rem_euclid(360.0f32) - 180.0f32;
+  lhs + delta * factor
+}
+
+// NOTE: pushes lightness of colorful slots apart while they look alike
+fn separate(
+  main: &mut ResultMain<Color>,
+  cvd: Cvd,
+  pinned: impl Fn(Slot) -> bool,
+) {
+  const ITERATIONS: usize = 100;
+  const STEP: f32 = 0.01f32;
+
+  // NOTE: linear channels here are the displayed 8 bit channels
+  let simulate = |color: Color| -> Color {
+    let rgb = IntoColor::<palette::LinSrgb<f32>>::into_color(color.color);
+    let [red, green, blue] =
+      super::cvd::simulate([rgb.red, rgb.green, rgb.blue], cvd.deficiency);
+    IntoColor::<Color>::into_color(palette::LinSrgba::new(
+      red,
+      green,
+      blue,
+      color.alpha,
+    ))
+  };
+
+  let colorful = Slot::ALL[1..7]
+    .iter()
+    .chain(Slot::ALL[9..15].iter())
+    .copied()
+    .collect::<Vec<_>>();
+  let mut moved = ResultMain::from([false; 16]);
+  let mut separated = false;
+  for _ in 0..ITERATIONS {
+    separated = true;
+    for (index, lhs) in colorful.iter().enumerate() {
+      for rhs in colorful.iter().skip(index + 1) {
+        let (lhs_color, rhs_color) = (*main.get(*lhs), *main.get(*rhs));
+        let difference =
+          distance(simulate(lhs_color), simulate(rhs_color), Metric::Oklab);
+        if difference >= cvd.threshold {
+          continue;
+        }
+        separated = false;
+
+        // NOTE: the lighter one goes lighter and the darker one darker
+        let direction = if lhs_color.l >= rhs_color.l {
+          1.0f32
+        } else {
+          -1.0f32
+        };
+        for (slot, direction) in [(*lhs, direction), (*rhs, -direction)] {
+          if pinned(slot) {
+            continue;
+          }
+          let color = main.get_mut(slot);
+          let mut hsla = IntoColor::<Hsla>::into_color(*color);
+          hsla.lightness =
+            (hsla.lightness + direction * STEP).clamp(0.0f32, 1.0f32);
+          *color = hsla.into_color();
+          *moved.get_mut(slot) = true;
+        }
+      }
+    }
+    if separated {
+      break;
+    }
+  }
+
+  if !separated {
+    tracing::warn!(
+      "Could not separate colors for {:?} by {} in {} iterations",
+      cvd.deficiency,
+      cvd.threshold,
+      ITERATIONS
+    );
+  }
+
+  for slot in Slot::ALL {
+    if *moved.get(slot) {
+      tracing::debug!(
+        "Separated {:?} for {:?} to {:?}",
+        slot,
+        cvd.deficiency,
+        to_rgba(*main.get(slot))
+      );
+    }
+  }
+}
+
+// NOTE: scales saturation and lightness down or towards white in light mode
+fn dim(color: Color, config: DimConfig, mode: Mode) -> Color {
+  let mut hsla = IntoColor::<Hsla>::into_color(color);
+  hsla.saturation *= config.saturation_factor;
+  hsla.lightness = match mode {
+    Mode::Dark => hsla.lightness * config.lightness_factor,
+    Mode::Light => 1.0f32 - (1.0f32 - hsla.lightness) * config.lightness_factor,
+  };
+  hsla.into_color()
+}
+
+// NOTE: moves lightness away from the other color until the contrast is met
+fn ensure_contrast(
+  slot: Slot,
+  color: Color,
+  other: Color,
+  contrast: Contrast,
+) -> Color {
+  let measure = |color: Color| {
+    let Rgba {
+      red, green, blue, ..
+    } = to_rgba(color);
+    let Rgba {
+      red: other_red,
+      green: other_green,
+      blue: other_blue,
+      ..
+    } = to_rgba(other);
+    match contrast.algorithm {
+      ContrastAlgorithm::Wcag => super::contrast::wcag(
+        (red, green, blue),
+        (other_red, other_green, other_blue),
+      ),
+      ContrastAlgorithm::Apca => super::contrast::apca(
+        (red, green, blue),
+        (other_red, other_green, other_blue),
+      )
+      .abs(),
+    }
+  };
+
+  let before = measure(color);
+  if before >= contrast.minimum {
+    return color;
+  }
+
+  let mut hsla = IntoColor::<Hsla>::into_color(color);
+  let original_lightness = hsla.lightness;
+  let other_lightness = IntoColor::<Hsla>::into_color(other).lightness;
+  let step = if other_lightness < 0.5f32 {
+    0.01f32
+  } else {
+    -0.01f32
+  };
+
+  let mut adjusted = color;
+  while (0.0f32..=1.0f32).contains(&(hsla.lightness + step)) {
+    hsla.lightness += step;
+    adjusted = hsla.into_color();
+    if measure(adjusted) >= contrast.minimum {
+      break;
+    }
+  }
+
+  let after = measure(adjusted);
+  tracing::debug!(
+    "Adjusted {:?} lightness from {} to {} for contrast {} -> {}",
+    slot,
+    original_lightness,
+    hsla.lightness,
+    before,
+    after
+  );
+  if after < contrast.minimum {
+    tracing::warn!(
+      "Could not reach contrast {} for {:?} - got {}",
+      contrast.minimum,
+      slot,
+      after
+    );
+  }
+
+  adjusted
+}
+
+fn constrain(color: Color, r#override: Override) -> Color {
+  if let Some(pin) = r#override.pin {
+    return from_reference(pin);
+  }
+
+  let mut hsla = palette::IntoColor::<Hsla>::into_color(color);
+  if let Some(min_saturation) = r#override.min_saturation {
+    hsla.saturation = hsla.saturation.max(min_saturation);
+  }
+  if let Some((from, to)) = r#override.hue {
+    // NOTE: the range goes counter-clockwise from the first to the second hue
+    let hue = hsla.hue.into_positive_degrees();
+    let from = from.rem_euclid(360.0f32);
+    let to = to.rem_euclid(360.0f32);
+    let arc = |from: f32, to: f32| (to - from).rem_euclid(360.0f32);
+    if arc(from, hue) > arc(from, to) {
+      hsla.hue = if arc(hue, from) < arc(to, hue) {
+        from.into()
+      } else {
+        to.into()
+      };
+    }
+  }
+  if let Some((min, max)) = r#override.lightness {
+    hsla.lightness = hsla.lightness.clamp(min.min(max), max.max(min));
+  }
 
-  Hsla::new(lhs_hsla.color.hue, saturation, lightness, rhs.alpha).into_color()
+  hsla.into_color()
 }
 
 fn opaque(r: u8, g: u8, b: u8) -> Color {
@@ -267,9 +1104,10 @@ fn print(reference: Color, closest: Color, result: Color, config: AreaConfig) {
 
   let lightness_factor = config.lightness_factor;
   let saturation_factor = config.saturation_factor;
+  let hue_factor = config.hue_factor;
 
   let _ = std::io::Write::write_all(
     &mut std::io::stdout(),
-    format!("{reference} -> ({closest}, {lightness_factor}, {saturation_factor}) -> {result}\n").as_bytes(),
+    format!("{reference} -> ({closest}, {lightness_factor}, {saturation_factor}, {hue_factor}) -> {result}\n").as_bytes(),
   );
 }
@@ -0,0 +1,101 @@
+use palette::IntoColor;
+
+use super::ansi::{Rgba, Slot};
+
+// NOTE: base10 to base17 are the base24 extension
+#[derive(Debug, Clone)]
+pub struct Result {
+  pub base00: Rgba,
+  pub base01: Rgba,
+  pub base02: Rgba,
+  pub base03: Rgba,
+  pub base04: Rgba,
+  pub base05: Rgba,
+  pub base06: Rgba,
+  pub base07: Rgba,
+  pub base08: Rgba,
+  pub base09: Rgba,
+  pub base0a: Rgba,
+  pub base0b: Rgba,
+  pub base0c: Rgba,
+  pub base0d: Rgba,
+  pub base0e: Rgba,
+  pub base0f: Rgba,
+  pub base10: Rgba,
+  pub base11: Rgba,
+  pub base12: Rgba,
+  pub base13: Rgba,
+  pub base14: Rgba,
+  pub base15: Rgba,
+  pub base16: Rgba,
+  pub base17: Rgba,
+}
+
+type Lcha = palette::Oklcha<f32>;
+
+// NOTE: positions of base00 to base07 between the background and foreground
+const RAMP: [f32; 8] = [
+  0.0f32, 0.08f32, 0.16f32, 0.35f32, 0.6f32, 0.8f32, 0.9f32, 1.0f32,
+];
+
+// NOTE: how far base10 and base11 go past base00 away from the foreground
+const DARKER: [f32; 2] = [0.04f32, 0.08f32];
+
+pub fn from(ansi: &super::ansi::Result) -> Result {
+  let slot =
+    |slot: Slot| super::ansi::from_rgba(&[ansi.main.get(slot).clone()])[0];
+  let ramp = |factor: f32| {
+    super::ansi::to_rgba(super::mix(
+      slot(Slot::Black),
+      slot(Slot::BrightWhite),
+      factor,
+    ))
+  };
+
+  // NOTE: orange halfway between red and yellow and brown a darker orange
+  let red = IntoColor::<Lcha>::into_color(slot(Slot::Red));
+  let yellow = IntoColor::<Lcha>::into_color(slot(Slot::Yellow));
+  let orange = Lcha::new(
+    (red.l + yellow.l) / 2.0f32,
+    (red.chroma + yellow.chroma) / 2.0f32,
+    super::ansi::lerp_hue(
+      red.hue.into_degrees(),
+      yellow.hue.into_degrees(),
+      0.5f32,
+    ),
+    1.0f32,
+  );
+  let brown = Lcha::new(
+    orange.l * 0.65f32,
+    orange.chroma * 0.7f32,
+    orange.hue,
+    1.0f32,
+  );
+
+  Result {
+    base00: ramp(RAMP[0]),
+    base01: ramp(RAMP[1]),
+    base02: ramp(RAMP[2]),
+    base03: ramp(RAMP[3]),
+    base04: ramp(RAMP[4]),
+    base05: ramp(RAMP[5]),
+    base06: ramp(RAMP[6]),
+    base07: ramp(RAMP[7]),
+    base08: super::ansi::to_rgba(slot(Slot::Red)),
+    base09: super::ansi::to_rgba(orange.into_color()),
+    base0a: super::ansi::to_rgba(slot(Slot::Yellow)),
+    base0b: super::ansi::to_rgba(slot(Slot::Green)),
+    base0c: super::ansi::to_rgba(slot(Slot::Cyan)),
+    base0d: super::ansi::to_rgba(slot(Slot::Blue)),
+    base0e: super::ansi::to_rgba(slot(Slot::Magenta)),
+    base0f: super::ansi::to_rgba(brown.into_color()),
+    base10: ramp(-DARKER[0]),
+    base11: ramp(-DARKER[1]),
+    base12: super::ansi::to_rgba(slot(Slot::BrightRed)),
+    base13: super::ansi::to_rgba(slot(Slot::BrightYellow)),
+    base14: super::ansi::to_rgba(slot(Slot::BrightGreen)),
+    base15: super::ansi::to_rgba(slot(Slot::BrightCyan)),
+    base16: super::ansi::to_rgba(slot(Slot::BrightBlue)),
+    base17: super::ansi::to_rgba(slot(Slot::BrightMagenta)),
+  }
+}
@@ -0,0 +1,65 @@
+// NOTE: both take colors as displayed 8 bit sRGB channels
+
+// NOTE: WCAG 2 relative luminance
+pub fn luminance((red, green, blue): (u8, u8, u8)) -> f32 {
+  let channel = |channel: u8| {
+    let channel = Into::<f32>::into(channel) / 255.0f32;
+    if channel <= 0.04045f32 {
+      channel / 12.92f32
+    } else {
+      ((channel + 0.055f32) / 1.055f32).powf(2.4f32)
+    }
+  };
+
+  0.2126f32 * channel(red)
+    + 0.7152f32 * channel(green)
+    + 0.0722f32 * channel(blue)
+}
+
+// NOTE: WCAG 2 contrast ratio from 1 to 21
+pub fn wcag(lhs: (u8, u8, u8), rhs: (u8, u8, u8)) -> f32 {
+  let lhs = luminance(lhs);
+  let rhs = luminance(rhs);
+  (lhs.max(rhs) + 0.05f32) / (lhs.min(rhs) + 0.05f32)
+}
+
+// NOTE: APCA lightness contrast from about -108 to 106
+// NOTE: positive for dark text on light background
+pub fn apca(text: (u8, u8, u8), background: (u8, u8, u8)) -> f32 {
+  let luminance = |(red, green, blue): (u8, u8, u8)| {
+    let channel =
+      |channel: u8| (Into::<f32>::into(channel) / 255.0f32).powf(2.4f32);
+    let luminance = 0.2126729f32 * channel(red)
+      + 0.7151522f32 * channel(green)
+      + 0.0721750f32 * channel(blue);
+    if luminance < 0.022f32 {
+      luminance + (0.022f32 - luminance).powf(1.414f32)
+    } else {
+      luminance
+    }
+  };
+
+  let text = luminance(text);
+  let background = luminance(background);
+  if (background - text).abs() < 0.0005f32 {
+    return 0.0f32;
+  }
+
+  let contrast = if background > text {
+    let contrast = (background.powf(0.56f32) - text.powf(0.57f32)) * 1.14f32;
+    if contrast < 0.1f32 {
+      0.0f32
+    } else {
+      contrast - 0.027f32
+    }
+  } else {
+    let contrast = (background.powf(0.65f32) - text.powf(0.62f32)) * 1.14f32;
+    if contrast > -0.1f32 {
+      0.0f32
+    } else {
+      contrast + 0.027f32
+    }
+  };
+
+  contrast * 100.0f32
+}
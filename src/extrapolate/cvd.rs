@@ -0,0 +1,48 @@
+// NOTE: Machado, Oliveira and Fernandes 2009 matrices at full severity
+// NOTE: takes and returns displayed sRGB channels from 0 to 1
+// NOTE: and applies the matrices to linearized channels in between
+
+#[derive(Debug, Clone, Copy)]
+pub enum Deficiency {
+  Protan,
+  Deutan,
+  Tritan,
+}
+
+const PROTAN: [[f32; 3]; 3] = [
+  [0.152286f32, 1.052583f32, -0.204868f32],
+  [0.114503f32, 0.786281f32, 0.099216f32],
+  [-0.003882f32, -0.048116f32, 1.051998f32],
+];
+
+const DEUTAN: [[f32; 3]; 3] = [
+  [0.367322f32, 0.860646f32, -0.227968f32],
+  [0.280085f32, 0.672501f32, 0.047413f32],
+  [-0.011820f32, 0.042940f32, 0.968881f32],
+];
+
+const TRITAN: [[f32; 3]; 3] = [
+  [1.255528f32, -0.076749f32, -0.178779f32],
+  [-0.078411f32, 0.930809f32, 0.147602f32],
+  [0.004733f32, 0.691367f32, 0.303900f32],
+];
+
+pub fn simulate(rgb: [f32; 3], deficiency: Deficiency) -> [f32; 3] {
+  let matrix = match deficiency {
+    Deficiency::Protan => PROTAN,
+    Deficiency::Deutan => DEUTAN,
+    Deficiency::Tritan => TRITAN,
+  };
+
+  let [red, green, blue] = rgb;
+  let linear = palette::Srgb::new(red, green, blue).into_linear::<f32>();
+  let linear = [linear.red, linear.green, linear.blue];
+  let [red, green, blue] = matrix.map(|row| {
+    (row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2])
+      .clamp(0.0f32, 1.0f32)
+  });
+  let encoded =
+    palette::Srgb::<f32>::from_linear(palette::LinSrgb::new(red, green, blue));
+
+  [encoded.red, encoded.green, encoded.blue]
+}
@@ -0,0 +1,143 @@
+// NOTE: Hungarian algorithm with potentials for rectangular matrices
+// NOTE: returns the column assigned to each row - needs rows <= columns
+// NOTE: assigns nothing when a row has no finite cost left
+
+pub fn solve(costs: &[Vec<f32>]) -> Vec<Option<usize>> {
+  let rows = costs.len();
+  let columns = costs.first().map(|row| row.len()).unwrap_or_default();
+  if rows == 0 || columns < rows {
+    return vec![None; rows];
+  }
+
+  let cost = |row: usize, column: usize| -> f64 {
+    costs
+      .get(row - 1)
+      .and_then(|costs| costs.get(column - 1))
+      .map(|cost| f64::from(*cost))
+      .unwrap_or(f64::INFINITY)
+  };
+
+  // NOTE: one-based with the zeroth column as a sentinel
+  let mut row_potentials = vec![0.0f64; rows + 1];
+  let mut column_potentials = vec![0.0f64; columns + 1];
+  let mut column_rows = vec![0usize; columns + 1];
+  let mut way = vec![0usize; columns + 1];
+
+  for row in 1..=rows {
+    column_rows[0] = row;
+    let mut current_column = 0usize;
+    let mut minimums = vec![f64::INFINITY; columns + 1];
+    let mut used = vec![false; columns + 1];
+
+    loop {
+      used[current_column] = true;
+      let current_row = column_rows[current_column];
+      let mut delta = f64::INFINITY;
+      let mut next_column = 0usize;
+
+      for column in 1..=columns {
+        if used[column] {
+          continue;
+        }
+
+        let reduced = cost(current_row, column)
+          - row_potentials[current_row]
+          - column_potentials[column];
+        if reduced < minimums[column] {
+          minimums[column] = reduced;
+          way[column] = current_column;
+        }
+        if minimums[column] < delta {
+          delta = minimums[column];
+          next_column = column;
+        }
+      }
+
+      for column in 0..=columns {
+        if used[column] {
+          row_potentials[column_rows[column]] += delta;
+          column_potentials[column] -= delta;
+        } else {
+          minimums[column] -= delta;
+        }
+      }
+
+      if !delta.is_finite() {
+        return vec![None; rows];
+      }
+
+      current_column = next_column;
+      if column_rows[current_column] == 0 {
+        break;
+      }
+    }
+
+    loop {
+      let previous_column = way[current_column];
+      column_rows[current_column] = column_rows[previous_column];
+      current_column = previous_column;
+      if current_column == 0 {
+        break;
+      }
+    }
+  }
+
+  let mut assignment = vec![None; rows];
+  for (column, row) in column_rows.iter().enumerate().skip(1) {
+    if *row > 0 {
+      if let Some(assigned) = assignment.get_mut(row - 1) {
+        *assigned = Some(column - 1);
+      }
+    }
+  }
+
+  assignment
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn total(costs: &[Vec<f32>], assignment: &[Option<usize>]) -> f32 {
+    assignment
+      .iter()
+      .enumerate()
+      .filter_map(|(row, column)| column.map(|column| costs[row][column]))
+      .sum()
+  }
+
+  #[test]
+  fn square() {
+    let costs = vec![
+      vec![4.0f32, 1.0f32, 3.0f32],
+      vec![2.0f32, 0.0f32, 5.0f32],
+      vec![3.0f32, 2.0f32, 2.0f32],
+    ];
+    let assignment = solve(&costs);
+    assert_eq!(assignment, vec![Some(1), Some(0), Some(2)]);
+    assert_eq!(total(&costs, &assignment), 5.0f32);
+  }
+
+  #[test]
+  fn rectangular() {
+    let costs = vec![
+      vec![7.0f32, 1.0f32, 9.0f32, 2.0f32],
+      vec![6.0f32, 1.5f32, 8.0f32, 3.0f32],
+    ];
+    let assignment = solve(&costs);
+    assert_eq!(assignment, vec![Some(3), Some(1)]);
+    assert_eq!(total(&costs, &assignment), 3.5f32);
+  }
+
+  #[test]
+  fn fewer_columns_than_rows() {
+    let costs = vec![vec![1.0f32], vec![2.0f32]];
+    assert_eq!(solve(&costs), vec![None, None]);
+  }
+
+  #[test]
+  fn not_a_number() {
+    let costs = vec![vec![f32::NAN, f32::NAN], vec![f32::NAN, f32::NAN]];
+    assert_eq!(solve(&costs), vec![None, None]);
+  }
+}
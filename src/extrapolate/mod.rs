@@ -1 +1,23 @@
+mod hungarian;
+
 pub mod ansi;
+pub mod base16;
+pub mod contrast;
+pub mod cvd;
+pub mod named;
+pub mod semantic;
+pub mod tonal;
+
+// NOTE: straight Oklab interpolation shared by everything that mixes colors
+pub(crate) fn mix(
+  lhs: palette::Oklaba<f32>,
+  rhs: palette::Oklaba<f32>,
+  factor: f32,
+) -> palette::Oklaba<f32> {
+  palette::Oklaba::new(
+    lhs.l + (rhs.l - lhs.l) * factor,
+    lhs.a + (rhs.a - lhs.a) * factor,
+    lhs.b + (rhs.b - lhs.b) * factor,
+    lhs.alpha + (rhs.alpha - lhs.alpha) * factor,
+  )
+}
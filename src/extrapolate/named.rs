@@ -0,0 +1,25 @@
+use super::ansi::{AreaConfig, Reference, Rgba};
+
+// NOTE: user supplied names like rosewater or surface0 with reference colors
+#[derive(Debug, Clone)]
+pub struct Config {
+  pub references: Vec<(String, Reference)>,
+  pub area: AreaConfig,
+}
+
+pub fn from(palette: &[Rgba], config: Config) -> Vec<(String, Rgba)> {
+  let palette = super::ansi::from_rgba(palette);
+
+  config
+    .references
+    .into_iter()
+    .map(|(name, reference)| {
+      let (_, mixed) = super::ansi::mix_closest_to(
+        &palette,
+        super::ansi::from_reference(reference),
+        config.area,
+      );
+      (name, super::ansi::to_rgba(mixed))
+    })
+    .collect()
+}
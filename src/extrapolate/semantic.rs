@@ -0,0 +1,204 @@
+use palette::IntoColor;
+
+use super::ansi::{Mode, Reference, Rgba, Slot};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+  pub overrides: Result<Option<Override>>,
+  pub mode: Mode,
+}
+
+// NOTE: replaces the derived color with an ANSI color or a fixed color
+#[derive(Debug, Clone, Copy)]
+pub enum Override {
+  Slot(Slot),
+  Color(Reference),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+  Background,
+  Foreground,
+  Cursor,
+  CursorText,
+  SelectionBackground,
+  SelectionForeground,
+  Url,
+  Accent,
+  Surface,
+  Border,
+  Muted,
+  Error,
+  Warning,
+  Success,
+  Info,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Result<T = Rgba> {
+  pub background: T,
+  pub foreground: T,
+  pub cursor: T,
+  pub cursor_text: T,
+  pub selection_background: T,
+  pub selection_foreground: T,
+  pub url: T,
+  pub accent: T,
+  pub surface: T,
+  pub border: T,
+  pub muted: T,
+  pub error: T,
+  pub warning: T,
+  pub success: T,
+  pub info: T,
+}
+
+type Color = palette::Oklaba<f32>;
+
+// NOTE: minimal lightness difference between the accent and background
+const MIN_ACCENT_LIGHTNESS: f32 = 0.35f32;
+
+impl Role {
+  pub const ALL: [Role; 15] = [
+    Role::Background,
+    Role::Foreground,
+    Role::Cursor,
+    Role::CursorText,
+    Role::SelectionBackground,
+    Role::SelectionForeground,
+    Role::Url,
+    Role::Accent,
+    Role::Surface,
+    Role::Border,
+    Role::Muted,
+    Role::Error,
+    Role::Warning,
+    Role::Success,
+    Role::Info,
+  ];
+}
+
+impl<T> Result<T> {
+  pub fn get(&self, role: Role) -> &T {
+    match role {
+      Role::Background => &self.background,
+      Role::Foreground => &self.foreground,
+      Role::Cursor => &self.cursor,
+      Role::CursorText => &self.cursor_text,
+      Role::SelectionBackground => &self.selection_background,
+      Role::SelectionForeground => &self.selection_foreground,
+      Role::Url => &self.url,
+      Role::Accent => &self.accent,
+      Role::Surface => &self.surface,
+      Role::Border => &self.border,
+      Role::Muted => &self.muted,
+      Role::Error => &self.error,
+      Role::Warning => &self.warning,
+      Role::Success => &self.success,
+      Role::Info => &self.info,
+    }
+  }
+
+  pub fn get_mut(&mut self, role: Role) -> &mut T {
+    match role {
+      Role::Background => &mut self.background,
+      Role::Foreground => &mut self.foreground,
+      Role::Cursor => &mut self.cursor,
+      Role::CursorText => &mut self.cursor_text,
+      Role::SelectionBackground => &mut self.selection_background,
+      Role::SelectionForeground => &mut self.selection_foreground,
+      Role::Url => &mut self.url,
+      Role::Accent => &mut self.accent,
+      Role::Surface => &mut self.surface,
+      Role::Border => &mut self.border,
+      Role::Muted => &mut self.muted,
+      Role::Error => &mut self.error,
+      Role::Warning => &mut self.warning,
+      Role::Success => &mut self.success,
+      Role::Info => &mut self.info,
+    }
+  }
+}
+
+pub fn from(
+  palette: &[Rgba],
+  weights: &[f32],
+  ansi: &super::ansi::Result,
+  config: Config,
+) -> Result {
+  let slot =
+    |slot: Slot| super::ansi::from_rgba(&[ansi.main.get(slot).clone()])[0];
+
+  let overridden = |role: Role, default: Color| match config.overrides.get(role)
+  {
+    Some(Override::Slot(overridden)) => slot(*overridden),
+    Some(Override::Color(reference)) => super::ansi::from_reference(*reference),
+    None => default,
+  };
+
+  let background = overridden(Role::Background, slot(Slot::Black));
+  let foreground = overridden(Role::Foreground, slot(Slot::White));
+  let accent = accent(palette, weights, background, config.mode)
+    .unwrap_or_else(|| slot(Slot::BrightBlue));
+
+  let mut result = Result {
+    background,
+    foreground,
+    cursor: foreground,
+    cursor_text: background,
+    selection_background: super::mix(background, accent, 0.35f32),
+    selection_foreground: foreground,
+    url: slot(Slot::BrightBlue),
+    accent,
+    surface: super::mix(background, foreground, 0.08f32),
+    border: super::mix(background, foreground, 0.2f32),
+    muted: super::mix(background, foreground, 0.5f32),
+    error: slot(Slot::Red),
+    warning: slot(Slot::Yellow),
+    success: slot(Slot::Green),
+    info: slot(Slot::Cyan),
+  };
+
+  for role in Role::ALL {
+    *result.get_mut(role) = overridden(role, *result.get(role));
+  }
+
+  Result {
+    background: super::ansi::to_rgba(result.background),
+    foreground: super::ansi::to_rgba(result.foreground),
+    cursor: super::ansi::to_rgba(result.cursor),
+    cursor_text: super::ansi::to_rgba(result.cursor_text),
+    selection_background: super::ansi::to_rgba(result.selection_background),
+    selection_foreground: super::ansi::to_rgba(result.selection_foreground),
+    url: super::ansi::to_rgba(result.url),
+    accent: super::ansi::to_rgba(result.accent),
+    surface: super::ansi::to_rgba(result.surface),
+    border: super::ansi::to_rgba(result.border),
+    muted: super::ansi::to_rgba(result.muted),
+    error: super::ansi::to_rgba(result.error),
+    warning: super::ansi::to_rgba(result.warning),
+    success: super::ansi::to_rgba(result.success),
+    info: super::ansi::to_rgba(result.info),
+  }
+}
+
+// NOTE: the most prominent colorful image color kept lighter than the
+// NOTE: background in dark mode and darker in light mode
+fn accent(
+  palette: &[Rgba],
+  weights: &[f32],
+  background: Color,
+  mode: Mode,
+) -> Option<Color> {
+  let mut accent = super::ansi::most_colorful(palette, weights)?;
+  accent.l = match mode {
+    Mode::Dark => accent
+      .l
+      .max((background.l + MIN_ACCENT_LIGHTNESS).min(1.0f32)),
+    Mode::Light => accent
+      .l
+      .min((background.l - MIN_ACCENT_LIGHTNESS).max(0.0f32)),
+  };
+
+  Some(accent.into_color())
+}
@@ -0,0 +1,228 @@
+use palette::convert::IntoColorUnclamped;
+use palette::{IntoColor, IsWithinBounds};
+
+use super::ansi::{Reference, Rgba};
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+  pub seed: Option<Reference>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Result {
+  pub seed: Rgba,
+  pub palettes: Palettes,
+  pub light: Scheme,
+  pub dark: Scheme,
+}
+
+#[derive(Debug, Clone)]
+pub struct Palettes {
+  pub primary: Tones,
+  pub secondary: Tones,
+  pub tertiary: Tones,
+  pub neutral: Tones,
+  pub neutral_variant: Tones,
+  pub error: Tones,
+}
+
+// NOTE: tone is the Oklch lightness times 100
+#[derive(Debug, Clone)]
+pub struct Tones {
+  pub t0: Rgba,
+  pub t10: Rgba,
+  pub t20: Rgba,
+  pub t30: Rgba,
+  pub t40: Rgba,
+  pub t50: Rgba,
+  pub t60: Rgba,
+  pub t70: Rgba,
+  pub t80: Rgba,
+  pub t90: Rgba,
+  pub t95: Rgba,
+  pub t99: Rgba,
+  pub t100: Rgba,
+}
+
+// NOTE: Material 3 color roles picked from tones of the palettes
+#[derive(Debug, Clone)]
+pub struct Scheme {
+  pub primary: Rgba,
+  pub on_primary: Rgba,
+  pub primary_container: Rgba,
+  pub on_primary_container: Rgba,
+  pub secondary: Rgba,
+  pub on_secondary: Rgba,
+  pub secondary_container: Rgba,
+  pub on_secondary_container: Rgba,
+  pub tertiary: Rgba,
+  pub on_tertiary: Rgba,
+  pub tertiary_container: Rgba,
+  pub on_tertiary_container: Rgba,
+  pub error: Rgba,
+  pub on_error: Rgba,
+  pub error_container: Rgba,
+  pub on_error_container: Rgba,
+  pub background: Rgba,
+  pub on_background: Rgba,
+  pub surface: Rgba,
+  pub on_surface: Rgba,
+  pub surface_variant: Rgba,
+  pub on_surface_variant: Rgba,
+  pub outline: Rgba,
+  pub outline_variant: Rgba,
+  pub shadow: Rgba,
+  pub scrim: Rgba,
+  pub inverse_surface: Rgba,
+  pub inverse_on_surface: Rgba,
+  pub inverse_primary: Rgba,
+}
+
+type Color = palette::Oklaba<f32>;
+type Lch = palette::Oklch<f32>;
+type ContinuousRgb = palette::Srgb<f32>;
+
+// NOTE: Oklch chroma is roughly HCT chroma divided by 400
+const PRIMARY_CHROMA: f32 = 0.12f32;
+const SECONDARY_CHROMA: f32 = 0.04f32;
+const TERTIARY_CHROMA: f32 = 0.06f32;
+const NEUTRAL_CHROMA: f32 = 0.01f32;
+const NEUTRAL_VARIANT_CHROMA: f32 = 0.02f32;
+const ERROR_CHROMA: f32 = 0.15f32;
+const ERROR_HUE: f32 = 29.0f32;
+const TERTIARY_ROTATION: f32 = 60.0f32;
+
+pub fn from(palette: &[Rgba], weights: &[f32], config: Config) -> Result {
+  let seed = match config.seed {
+    Some(reference) => super::ansi::from_reference(reference),
+    None => seed(palette, weights),
+  };
+  let seed = IntoColor::<Lch>::into_color(seed);
+  let hue = seed.hue.into_degrees();
+
+  let palettes = Palettes {
+    primary: tones(hue, seed.chroma.max(PRIMARY_CHROMA)),
+    secondary: tones(hue, SECONDARY_CHROMA),
+    tertiary: tones(hue + TERTIARY_ROTATION, TERTIARY_CHROMA),
+    neutral: tones(hue, NEUTRAL_CHROMA),
+    neutral_variant: tones(hue, NEUTRAL_VARIANT_CHROMA),
+    error: tones(ERROR_HUE, ERROR_CHROMA),
+  };
+
+  Result {
+    seed: super::ansi::to_rgba(seed.into_color()),
+    light: light(&palettes),
+    dark: dark(&palettes),
+    palettes,
+  }
+}
+
+// NOTE: the most prominent colorful image color
+fn seed(palette: &[Rgba], weights: &[f32]) -> Color {
+  super::ansi::most_colorful(palette, weights)
+    .map(|color| color.into_color())
+    .unwrap_or_else(|| Color::new(0.5f32, 0.0f32, 0.0f32, 1.0f32))
+}
+
+fn tones(hue: f32, chroma: f32) -> Tones {
+  Tones {
+    t0: tone(hue, chroma, 0),
+    t10: tone(hue, chroma, 10),
+    t20: tone(hue, chroma, 20),
+    t30: tone(hue, chroma, 30),
+    t40: tone(hue, chroma, 40),
+    t50: tone(hue, chroma, 50),
+    t60: tone(hue, chroma, 60),
+    t70: tone(hue, chroma, 70),
+    t80: tone(hue, chroma, 80),
+    t90: tone(hue, chroma, 90),
+    t95: tone(hue, chroma, 95),
+    t99: tone(hue, chroma, 99),
+    t100: tone(hue, chroma, 100),
+  }
+}
+
+// NOTE: reduces chroma until the tone fits into sRGB
+fn tone(hue: f32, chroma: f32, tone: u8) -> Rgba {
+  let lightness = Into::<f32>::into(tone) / 100.0f32;
+  let mut chroma = chroma;
+  loop {
+    let color = Lch::new(lightness, chroma, hue);
+    let rgb = IntoColorUnclamped::<ContinuousRgb>::into_color_unclamped(color);
+    if rgb.is_within_bounds() || chroma <= 0.0f32 {
+      let Lch { l, chroma, hue } = color;
+      return super::ansi::to_rgba(
+        palette::Oklcha::new(l, chroma, hue, 1.0f32).into_color(),
+      );
+    }
+    chroma = (chroma - 0.005f32).max(0.0f32);
+  }
+}
+
+fn light(palettes: &Palettes) -> Scheme {
+  Scheme {
+    primary: palettes.primary.t40.clone(),
+    on_primary: palettes.primary.t100.clone(),
+    primary_container: palettes.primary.t90.clone(),
+    on_primary_container: palettes.primary.t10.clone(),
+    secondary: palettes.secondary.t40.clone(),
+    on_secondary: palettes.secondary.t100.clone(),
+    secondary_container: palettes.secondary.t90.clone(),
+    on_secondary_container: palettes.secondary.t10.clone(),
+    tertiary: palettes.tertiary.t40.clone(),
+    on_tertiary: palettes.tertiary.t100.clone(),
+    tertiary_container: palettes.tertiary.t90.clone(),
+    on_tertiary_container: palettes.tertiary.t10.clone(),
+    error: palettes.error.t40.clone(),
+    on_error: palettes.error.t100.clone(),
+    error_container: palettes.error.t90.clone(),
+    on_error_container: palettes.error.t10.clone(),
+    background: palettes.neutral.t99.clone(),
+    on_background: palettes.neutral.t10.clone(),
+    surface: palettes.neutral.t99.clone(),
+    on_surface: palettes.neutral.t10.clone(),
+    surface_variant: palettes.neutral_variant.t90.clone(),
+    on_surface_variant: palettes.neutral_variant.t30.clone(),
+    outline: palettes.neutral_variant.t50.clone(),
+    outline_variant: palettes.neutral_variant.t80.clone(),
+    shadow: palettes.neutral.t0.clone(),
+    scrim: palettes.neutral.t0.clone(),
+    inverse_surface: palettes.neutral.t20.clone(),
+    inverse_on_surface: palettes.neutral.t95.clone(),
+    inverse_primary: palettes.primary.t80.clone(),
+  }
+}
+
+fn dark(palettes: &Palettes) -> Scheme {
+  Scheme {
+    primary: palettes.primary.t80.clone(),
+    on_primary: palettes.primary.t20.clone(),
+    primary_container: palettes.primary.t30.clone(),
+    on_primary_container: palettes.primary.t90.clone(),
+    secondary: palettes.secondary.t80.clone(),
+    on_secondary: palettes.secondary.t20.clone(),
+    secondary_container: palettes.secondary.t30.clone(),
+    on_secondary_container: palettes.secondary.t90.clone(),
+    tertiary: palettes.tertiary.t80.clone(),
+    on_tertiary: palettes.tertiary.t20.clone(),
+    tertiary_container: palettes.tertiary.t30.clone(),
+    on_tertiary_container: palettes.tertiary.t90.clone(),
+    error: palettes.error.t80.clone(),
+    on_error: palettes.error.t20.clone(),
+    error_container: palettes.error.t30.clone(),
+    on_error_container: palettes.error.t90.clone(),
+    background: palettes.neutral.t10.clone(),
+    on_background: palettes.neutral.t90.clone(),
+    surface: palettes.neutral.t10.clone(),
+    on_surface: palettes.neutral.t90.clone(),
+    surface_variant: palettes.neutral_variant.t30.clone(),
+    on_surface_variant: palettes.neutral_variant.t80.clone(),
+    outline: palettes.neutral_variant.t60.clone(),
+    outline_variant: palettes.neutral_variant.t30.clone(),
+    shadow: palettes.neutral.t0.clone(),
+    scrim: palettes.neutral.t0.clone(),
+    inverse_surface: palettes.neutral.t90.clone(),
+    inverse_on_surface: palettes.neutral.t20.clone(),
+    inverse_primary: palettes.primary.t40.clone(),
+  }
+}
@@ -43,135 +43,391 @@ async fn main() -> anyhow::Result<()> {
     args::Args::Plop { config, .. } => config.location.clone(),
     args::Args::Print { config, .. } => config.location.clone(),
   };
-  let config = config::read(config_location).await?;
+  let mut config = config::read(config_location).await?;
 
   let generation = match &args {
     args::Args::Plop { generation, .. } => generation.clone(),
     args::Args::Print { generation, .. } => generation.clone(),
   };
-  let mut palette = match generation.backend {
-    args::Backend::Kmeans => {
-      let kmeans_config = colors::kmeans::KmeansConfig {
-        runs: config.kmeans.runs,
-        k: config.kmeans.k,
-        converge: config.kmeans.converge,
-        max_iter: config.kmeans.max_iter,
-      };
-      colors::kmeans::prominent(generation.image, kmeans_config).await?
-    }
-    args::Backend::Colorthief => {
-      let colorthief_config = colors::colorthief::ColorthiefConfig {
-        quality: config.colorthief.quality,
-        max_colors: config.colorthief.max_colors,
-      };
-      colors::colorthief::prominent(generation.image, colorthief_config).await?
-    }
-    args::Backend::KmeansGpu => {
-      let kmeans_gpu_config = colors::kmeans_gpu::KmeansGpuConfig {
-        runs: config.kmeans_gpu.runs,
-        k: config.kmeans_gpu.k,
-        converge: config.kmeans_gpu.converge,
-        max_iter: config.kmeans_gpu.max_iter,
-      };
-      colors::kmeans_gpu::prominent(generation.image, kmeans_gpu_config).await?
+  let theme = match &generation.theme {
+    Some(path) => Some(colors::theme::read(path.clone()).await?),
+    None => None,
+  };
+  let mut palette = if let Some(theme) = &theme {
+    theme.colors()?
+  } else if let Some(list) = &generation.colors {
+    colors::explicit::from_list(list)?
+  } else if let Some(path) = &generation.palette {
+    colors::explicit::from_file(path.clone()).await?
+  } else {
+    let image = generation.image.clone().ok_or_else(|| {
+      anyhow::anyhow!("Expected an image, --colors or --palette")
+    })?;
+    match generation.backend {
+      args::Backend::Kmeans => {
+        let kmeans_config = colors::kmeans::KmeansConfig {
+          runs: config.kmeans.runs,
+          k: config.kmeans.k,
+          converge: config.kmeans.converge,
+          max_iter: config.kmeans.max_iter,
+        };
+        colors::kmeans::prominent(image, kmeans_config).await?
+      }
+      args::Backend::Colorthief => {
+        let colorthief_config = colors::colorthief::ColorthiefConfig {
+          quality: config.colorthief.quality,
+          max_colors: config.colorthief.max_colors,
+        };
+        colors::colorthief::prominent(image, colorthief_config).await?
+      }
+      args::Backend::KmeansGpu => {
+        let kmeans_gpu_config = colors::kmeans_gpu::KmeansGpuConfig {
+          runs: config.kmeans_gpu.runs,
+          k: config.kmeans_gpu.k,
+          converge: config.kmeans_gpu.converge,
+          max_iter: config.kmeans_gpu.max_iter,
+        };
+        colors::kmeans_gpu::prominent(image, kmeans_gpu_config).await?
+      }
+      args::Backend::MedianCut => {
+        let median_cut_config = colors::median_cut::MedianCutConfig {
+          iterations: config.median_cut.iterations,
+        };
+        colors::median_cut::prominent(image, median_cut_config).await?
+      }
+      args::Backend::Neoquant => {
+        let neoquant_config = colors::neoquant::NeoquantConfig {
+          sample_faction: config.neoquant.sample_faction,
+          colors: config.neoquant.colors,
+        };
+        colors::neoquant::prominent(image, neoquant_config).await?
+      }
+      args::Backend::Scolorq => {
+        let scolorq_config = colors::scolorq::ScolorqConfig {
+          size: config.scolorq.size,
+          dither: config.scolorq.dither,
+          seed: config.scolorq.seed,
+          filter: match config.scolorq.filter {
+            config::ScolorqConfigFilter::One => {
+              colors::scolorq::ScolorqConfigFilter::One
+            }
+            config::ScolorqConfigFilter::Three => {
+              colors::scolorq::ScolorqConfigFilter::Three
+            }
+            config::ScolorqConfigFilter::Five => {
+              colors::scolorq::ScolorqConfigFilter::Five
+            }
+          },
+          iters: config.scolorq.iters,
+          repeats: config.scolorq.repeats,
+          start_temp: config.scolorq.start_temp,
+          end_temp: config.scolorq.end_temp,
+        };
+        colors::scolorq::prominent(image, scolorq_config).await?
+      }
     }
-    args::Backend::MedianCut => {
-      let median_cut_config = colors::median_cut::MedianCutConfig {
-        iterations: config.median_cut.iterations,
-      };
-      colors::median_cut::prominent(generation.image, median_cut_config).await?
+  };
+
+  let source = palette
+    .palette
+    .iter()
+    .zip(palette.weights.iter())
+    .map(|(color, weight)| print::Source {
+      color: colors_to_print(color.clone()),
+      weight: *weight,
+    })
+    .collect::<Vec<_>>();
+
+  let mut references =
+    extrapolate::ansi::references(match config.ansi.references.preset {
+      config::AnsiReferencesPreset::Default => {
+        extrapolate::ansi::Preset::Default
+      }
+      config::AnsiReferencesPreset::Xterm => extrapolate::ansi::Preset::Xterm,
+      config::AnsiReferencesPreset::Vga => extrapolate::ansi::Preset::Vga,
+      config::AnsiReferencesPreset::Tango => extrapolate::ansi::Preset::Tango,
+      config::AnsiReferencesPreset::Solarized => {
+        extrapolate::ansi::Preset::Solarized
+      }
+    });
+  for (slot, color) in config.ansi.references.colors.iter() {
+    *references.get_mut(slot_to_ansi(*slot)) = color_to_reference(*color);
+  }
+
+  if let (Some(theme), args::Import::Direct) = (&theme, &generation.import) {
+    for (slot, color) in THEME_SLOTS.iter().zip(theme.ansi.iter()) {
+      if let Some(color) = color {
+        config.ansi.overrides.entry(*slot).or_default().color =
+          Some(colors_to_config(color));
+      }
     }
-    args::Backend::Neoquant => {
-      let neoquant_config = colors::neoquant::NeoquantConfig {
-        sample_faction: config.neoquant.sample_faction,
-        colors: config.neoquant.colors,
-      };
-      colors::neoquant::prominent(generation.image, neoquant_config).await?
+    for (role, color) in [
+      (config::SemanticRole::Background, &theme.background),
+      (config::SemanticRole::Foreground, &theme.foreground),
+      (config::SemanticRole::Cursor, &theme.cursor),
+    ] {
+      if let Some(color) = color {
+        config.semantic.overrides.insert(
+          role,
+          config::SemanticOverrideConfig::Color(colors_to_config(color)),
+        );
+      }
     }
-    args::Backend::Scolorq => {
-      let scolorq_config = colors::scolorq::ScolorqConfig {
-        size: config.scolorq.size,
-        dither: config.scolorq.dither,
-        seed: config.scolorq.seed,
-        filter: match config.scolorq.filter {
-          config::ScolorqConfigFilter::One => {
-            colors::scolorq::ScolorqConfigFilter::One
-          }
-          config::ScolorqConfigFilter::Three => {
-            colors::scolorq::ScolorqConfigFilter::Three
-          }
-          config::ScolorqConfigFilter::Five => {
-            colors::scolorq::ScolorqConfigFilter::Five
-          }
-        },
-        iters: config.scolorq.iters,
-        repeats: config.scolorq.repeats,
-        start_temp: config.scolorq.start_temp,
-        end_temp: config.scolorq.end_temp,
-      };
-      colors::scolorq::prominent(generation.image, scolorq_config).await?
+  }
+
+  for r#override in generation.overrides.iter() {
+    let (slot, color) = r#override.split_once('=').ok_or_else(|| {
+      anyhow::anyhow!("Invalid override {} - expected NAME=COLOR", r#override)
+    })?;
+    config
+      .ansi
+      .overrides
+      .entry(slot.parse()?)
+      .or_default()
+      .color = Some(color.parse()?);
+  }
+  let mut overrides =
+    extrapolate::ansi::ResultMain::<extrapolate::ansi::Override>::default();
+  for (slot, r#override) in config.ansi.overrides.iter() {
+    *overrides.get_mut(slot_to_ansi(*slot)) = extrapolate::ansi::Override {
+      pin: r#override.color.map(color_to_reference),
+      min_saturation: r#override.min_saturation,
+      hue: r#override.hue,
+      lightness: r#override.lightness,
+    };
+  }
+
+  let extrapolate_palette = palette
+    .palette
+    .drain(0..)
+    .map(
+      |colors::Rgba {
+         red,
+         green,
+         blue,
+         alpha,
+       }| extrapolate::ansi::Rgba {
+        red,
+        green,
+        blue,
+        alpha,
+      },
+    )
+    .collect::<Vec<_>>();
+  let mode = match generation.mode {
+    args::Mode::Dark => extrapolate::ansi::Mode::Dark,
+    args::Mode::Light => extrapolate::ansi::Mode::Light,
+    args::Mode::Auto => {
+      extrapolate::ansi::mode(&extrapolate_palette, &palette.weights)
     }
   };
 
   let mut ansi = extrapolate::ansi::from(
-    palette
-      .palette
-      .drain(0..)
-      .map(
-        |colors::Rgba {
-           red,
-           green,
-           blue,
-           alpha,
-         }| extrapolate::ansi::Rgba {
-          red,
-          green,
-          blue,
-          alpha,
-        },
-      )
-      .collect(),
+    extrapolate_palette.clone(),
     extrapolate::ansi::Config {
       main: extrapolate::ansi::AreaConfig {
         saturation_factor: config.ansi.main.saturation_factor,
         lightness_factor: config.ansi.main.lightness_factor,
+        hue_factor: config.ansi.main.hue_factor,
+        space: area_space(&config.ansi.main),
+        metric: area_metric(&config.ansi.main),
       },
       gradient: extrapolate::ansi::AreaConfig {
         saturation_factor: config.ansi.gradient.saturation_factor,
         lightness_factor: config.ansi.gradient.lightness_factor,
+        hue_factor: config.ansi.gradient.hue_factor,
+        space: area_space(&config.ansi.gradient),
+        metric: area_metric(&config.ansi.gradient),
       },
       grayscale: extrapolate::ansi::AreaConfig {
         saturation_factor: config.ansi.grayscale.saturation_factor,
         lightness_factor: config.ansi.grayscale.lightness_factor,
+        hue_factor: config.ansi.grayscale.hue_factor,
+        space: area_space(&config.ansi.grayscale),
+        metric: area_metric(&config.ansi.grayscale),
       },
+      dim: extrapolate::ansi::DimConfig {
+        saturation_factor: config.ansi.dim.saturation_factor,
+        lightness_factor: config.ansi.dim.lightness_factor,
+      },
+      references,
+      overrides,
+      assignment: match config.ansi.assignment {
+        config::AnsiAssignmentConfig::Independent => {
+          extrapolate::ansi::Assignment::Independent
+        }
+        config::AnsiAssignmentConfig::Unique => {
+          extrapolate::ansi::Assignment::Unique {
+            reuse_penalty: config.ansi.reuse_penalty,
+          }
+        }
+      },
+      contrast: config.ansi.contrast.as_ref().map(|contrast| {
+        extrapolate::ansi::Contrast {
+          algorithm: match contrast.algorithm {
+            config::AnsiContrastAlgorithmConfig::Wcag => {
+              extrapolate::ansi::ContrastAlgorithm::Wcag
+            }
+            config::AnsiContrastAlgorithmConfig::Apca => {
+              extrapolate::ansi::ContrastAlgorithm::Apca
+            }
+          },
+          minimum: contrast
+            .minimum
+            .unwrap_or_else(|| contrast.algorithm.default_minimum()),
+          background: slot_to_ansi(contrast.background),
+          foreground: slot_to_ansi(contrast.foreground),
+        }
+      }),
+      cvd: config.ansi.cvd.as_ref().map(|cvd| extrapolate::ansi::Cvd {
+        deficiency: match cvd.deficiency {
+          config::CvdDeficiencyConfig::Protan => {
+            extrapolate::cvd::Deficiency::Protan
+          }
+          config::CvdDeficiencyConfig::Deutan => {
+            extrapolate::cvd::Deficiency::Deutan
+          }
+          config::CvdDeficiencyConfig::Tritan => {
+            extrapolate::cvd::Deficiency::Tritan
+          }
+        },
+        threshold: cvd.threshold,
+      }),
+      mode,
+    },
+  );
+
+  let mut semantic_overrides = extrapolate::semantic::Result::<
+    Option<extrapolate::semantic::Override>,
+  >::default();
+  for (role, r#override) in config.semantic.overrides.iter() {
+    *semantic_overrides.get_mut(role_to_semantic(*role)) =
+      Some(match r#override {
+        config::SemanticOverrideConfig::Slot(slot) => {
+          extrapolate::semantic::Override::Slot(slot_to_ansi(*slot))
+        }
+        config::SemanticOverrideConfig::Color(color) => {
+          extrapolate::semantic::Override::Color(color_to_reference(*color))
+        }
+      });
+  }
+  let base16 = extrapolate::base16::from(&ansi);
+  let named = extrapolate::named::from(
+    &extrapolate_palette,
+    extrapolate::named::Config {
+      references: config
+        .named
+        .colors
+        .iter()
+        .map(|(name, color)| (name.clone(), color_to_reference(*color)))
+        .collect(),
+      area: extrapolate::ansi::AreaConfig {
+        saturation_factor: config.named.mix.saturation_factor,
+        lightness_factor: config.named.mix.lightness_factor,
+        hue_factor: config.named.mix.hue_factor,
+        space: area_space(&config.named.mix),
+        metric: area_metric(&config.named.mix),
+      },
+    },
+  );
+  let tonal = extrapolate::tonal::from(
+    &extrapolate_palette,
+    &palette.weights,
+    extrapolate::tonal::Config {
+      seed: config.tonal.seed.map(color_to_reference),
+    },
+  );
+  let semantic = extrapolate::semantic::from(
+    &extrapolate_palette,
+    &palette.weights,
+    &ansi,
+    extrapolate::semantic::Config {
+      overrides: semantic_overrides,
+      mode,
     },
   );
 
   match args {
     args::Args::Plop { .. } => {
+      let indexed = ansi.indexed();
       plop::many(
         plop::Context {
+          mode: match mode {
+            extrapolate::ansi::Mode::Dark => plop::Mode::Dark,
+            extrapolate::ansi::Mode::Light => plop::Mode::Light,
+          },
           ansi: plop::Ansi {
-            main: plop::AnsiMain {
-              black: ansi_to_plop(ansi.main.black),
-              red: ansi_to_plop(ansi.main.red),
-              green: ansi_to_plop(ansi.main.green),
-              blue: ansi_to_plop(ansi.main.blue),
-              cyan: ansi_to_plop(ansi.main.cyan),
-              yellow: ansi_to_plop(ansi.main.yellow),
-              magenta: ansi_to_plop(ansi.main.magenta),
-              white: ansi_to_plop(ansi.main.white),
-              bright_black: ansi_to_plop(ansi.main.bright_black),
-              bright_red: ansi_to_plop(ansi.main.bright_red),
-              bright_green: ansi_to_plop(ansi.main.bright_green),
-              bright_blue: ansi_to_plop(ansi.main.bright_blue),
-              bright_cyan: ansi_to_plop(ansi.main.bright_cyan),
-              bright_yellow: ansi_to_plop(ansi.main.bright_yellow),
-              bright_magenta: ansi_to_plop(ansi.main.bright_magenta),
-              bright_white: ansi_to_plop(ansi.main.bright_white),
-            },
+            main: main_to_plop(ansi.main),
+            dim: main_to_plop(ansi.dim),
             gradient: ansi.gradient.drain(0..).map(ansi_to_plop).collect(),
             grayscale: ansi.grayscale.drain(0..).map(ansi_to_plop).collect(),
+            indexed: indexed
+              .into_iter()
+              .enumerate()
+              .map(|(index, color)| {
+                (format!("color{index}"), ansi_to_plop(color))
+              })
+              .collect(),
+          },
+          semantic: plop::Semantic {
+            background: ansi_to_plop(semantic.background),
+            foreground: ansi_to_plop(semantic.foreground),
+            cursor: ansi_to_plop(semantic.cursor),
+            cursor_text: ansi_to_plop(semantic.cursor_text),
+            selection_background: ansi_to_plop(semantic.selection_background),
+            selection_foreground: ansi_to_plop(semantic.selection_foreground),
+            url: ansi_to_plop(semantic.url),
+            accent: ansi_to_plop(semantic.accent),
+            surface: ansi_to_plop(semantic.surface),
+            border: ansi_to_plop(semantic.border),
+            muted: ansi_to_plop(semantic.muted),
+            error: ansi_to_plop(semantic.error),
+            warning: ansi_to_plop(semantic.warning),
+            success: ansi_to_plop(semantic.success),
+            info: ansi_to_plop(semantic.info),
+          },
+          tonal: plop::Tonal {
+            seed: ansi_to_plop(tonal.seed),
+            palettes: plop::TonalPalettes {
+              primary: tones_to_plop(tonal.palettes.primary),
+              secondary: tones_to_plop(tonal.palettes.secondary),
+              tertiary: tones_to_plop(tonal.palettes.tertiary),
+              neutral: tones_to_plop(tonal.palettes.neutral),
+              neutral_variant: tones_to_plop(tonal.palettes.neutral_variant),
+              error: tones_to_plop(tonal.palettes.error),
+            },
+            light: scheme_to_plop(tonal.light),
+            dark: scheme_to_plop(tonal.dark),
+          },
+          named: named
+            .into_iter()
+            .map(|(name, color)| (name, ansi_to_plop(color)))
+            .collect(),
+          base16: plop::Base16 {
+            base00: ansi_to_plop(base16.base00),
+            base01: ansi_to_plop(base16.base01),
+            base02: ansi_to_plop(base16.base02),
+            base03: ansi_to_plop(base16.base03),
+            base04: ansi_to_plop(base16.base04),
+            base05: ansi_to_plop(base16.base05),
+            base06: ansi_to_plop(base16.base06),
+            base07: ansi_to_plop(base16.base07),
+            base08: ansi_to_plop(base16.base08),
+            base09: ansi_to_plop(base16.base09),
+            base0a: ansi_to_plop(base16.base0a),
+            base0b: ansi_to_plop(base16.base0b),
+            base0c: ansi_to_plop(base16.base0c),
+            base0d: ansi_to_plop(base16.base0d),
+            base0e: ansi_to_plop(base16.base0e),
+            base0f: ansi_to_plop(base16.base0f),
+            base10: ansi_to_plop(base16.base10),
+            base11: ansi_to_plop(base16.base11),
+            base12: ansi_to_plop(base16.base12),
+            base13: ansi_to_plop(base16.base13),
+            base14: ansi_to_plop(base16.base14),
+            base15: ansi_to_plop(base16.base15),
+            base16: ansi_to_plop(base16.base16),
+            base17: ansi_to_plop(base16.base17),
           },
         },
         plop::Config {
@@ -197,35 +453,239 @@ async fn main() -> anyhow::Result<()> {
       )
       .await?;
     }
-    args::Args::Print { format, .. } => {
+    args::Args::Print {
+      format,
+      color,
+      show,
+      sort,
+      output,
+      thumbnail,
+      background,
+      simulate,
+      generation,
+      ..
+    } => {
+      let color_mode = match color {
+        args::Color::Always => print::ColorMode::Always,
+        args::Color::Never => print::ColorMode::Never,
+        args::Color::Auto => print::ColorMode::Auto,
+        args::Color::Indexed => print::ColorMode::Indexed,
+        args::Color::Basic => print::ColorMode::Basic,
+      };
+      let show = show
+        .iter()
+        .map(|show| match show {
+          args::Show::Ansi => print::Show::Ansi,
+          args::Show::Source => print::Show::Source,
+          args::Show::Semantic => print::Show::Semantic,
+        })
+        .collect::<Vec<_>>();
+      let sort = match sort {
+        args::Sort::Weight => print::Sort::Weight,
+        args::Sort::Hue => print::Sort::Hue,
+      };
       let colors = print::Colors {
+        source,
         ansi: print::Ansi {
-          main: print::AnsiMain {
-            black: ansi_to_print(ansi.main.black),
-            red: ansi_to_print(ansi.main.red),
-            green: ansi_to_print(ansi.main.green),
-            blue: ansi_to_print(ansi.main.blue),
-            cyan: ansi_to_print(ansi.main.cyan),
-            yellow: ansi_to_print(ansi.main.yellow),
-            magenta: ansi_to_print(ansi.main.magenta),
-            white: ansi_to_print(ansi.main.white),
-            bright_black: ansi_to_print(ansi.main.bright_black),
-            bright_red: ansi_to_print(ansi.main.bright_red),
-            bright_green: ansi_to_print(ansi.main.bright_green),
-            bright_blue: ansi_to_print(ansi.main.bright_blue),
-            bright_cyan: ansi_to_print(ansi.main.bright_cyan),
-            bright_yellow: ansi_to_print(ansi.main.bright_yellow),
-            bright_magenta: ansi_to_print(ansi.main.bright_magenta),
-            bright_white: ansi_to_print(ansi.main.bright_white),
-          },
+          main: main_to_print(ansi.main),
+          dim: main_to_print(ansi.dim),
           gradient: ansi.gradient.drain(0..).map(ansi_to_print).collect(),
           grayscale: ansi.grayscale.drain(0..).map(ansi_to_print).collect(),
+          picks: print::AnsiMain {
+            black: ansi.picks.black,
+            red: ansi.picks.red,
+            green: ansi.picks.green,
+            blue: ansi.picks.blue,
+            cyan: ansi.picks.cyan,
+            yellow: ansi.picks.yellow,
+            magenta: ansi.picks.magenta,
+            white: ansi.picks.white,
+            bright_black: ansi.picks.bright_black,
+            bright_red: ansi.picks.bright_red,
+            bright_green: ansi.picks.bright_green,
+            bright_blue: ansi.picks.bright_blue,
+            bright_cyan: ansi.picks.bright_cyan,
+            bright_yellow: ansi.picks.bright_yellow,
+            bright_magenta: ansi.picks.bright_magenta,
+            bright_white: ansi.picks.bright_white,
+          },
+          pinned: print::AnsiMain {
+            black: ansi.pinned.black,
+            red: ansi.pinned.red,
+            green: ansi.pinned.green,
+            blue: ansi.pinned.blue,
+            cyan: ansi.pinned.cyan,
+            yellow: ansi.pinned.yellow,
+            magenta: ansi.pinned.magenta,
+            white: ansi.pinned.white,
+            bright_black: ansi.pinned.bright_black,
+            bright_red: ansi.pinned.bright_red,
+            bright_green: ansi.pinned.bright_green,
+            bright_blue: ansi.pinned.bright_blue,
+            bright_cyan: ansi.pinned.bright_cyan,
+            bright_yellow: ansi.pinned.bright_yellow,
+            bright_magenta: ansi.pinned.bright_magenta,
+            bright_white: ansi.pinned.bright_white,
+          },
+        },
+        semantic: print::Semantic {
+          background: ansi_to_print(semantic.background),
+          foreground: ansi_to_print(semantic.foreground),
+          cursor: ansi_to_print(semantic.cursor),
+          cursor_text: ansi_to_print(semantic.cursor_text),
+          selection_background: ansi_to_print(semantic.selection_background),
+          selection_foreground: ansi_to_print(semantic.selection_foreground),
+          url: ansi_to_print(semantic.url),
+          accent: ansi_to_print(semantic.accent),
+          surface: ansi_to_print(semantic.surface),
+          border: ansi_to_print(semantic.border),
+          muted: ansi_to_print(semantic.muted),
+          error: ansi_to_print(semantic.error),
+          warning: ansi_to_print(semantic.warning),
+          success: ansi_to_print(semantic.success),
+          info: ansi_to_print(semantic.info),
+        },
+        base16: print::Base16 {
+          base00: ansi_to_print(base16.base00),
+          base01: ansi_to_print(base16.base01),
+          base02: ansi_to_print(base16.base02),
+          base03: ansi_to_print(base16.base03),
+          base04: ansi_to_print(base16.base04),
+          base05: ansi_to_print(base16.base05),
+          base06: ansi_to_print(base16.base06),
+          base07: ansi_to_print(base16.base07),
+          base08: ansi_to_print(base16.base08),
+          base09: ansi_to_print(base16.base09),
+          base0a: ansi_to_print(base16.base0a),
+          base0b: ansi_to_print(base16.base0b),
+          base0c: ansi_to_print(base16.base0c),
+          base0d: ansi_to_print(base16.base0d),
+          base0e: ansi_to_print(base16.base0e),
+          base0f: ansi_to_print(base16.base0f),
+          base10: ansi_to_print(base16.base10),
+          base11: ansi_to_print(base16.base11),
+          base12: ansi_to_print(base16.base12),
+          base13: ansi_to_print(base16.base13),
+          base14: ansi_to_print(base16.base14),
+          base15: ansi_to_print(base16.base15),
+          base16: ansi_to_print(base16.base16),
+          base17: ansi_to_print(base16.base17),
         },
       };
 
+      let name = scheme_name(
+        generation
+          .image
+          .as_ref()
+          .or(generation.palette.as_ref())
+          .or(generation.theme.as_ref()),
+      );
       match format {
-        args::Format::List => print::list::from(colors).await?,
-        args::Format::Grid => print::grid::from(colors).await?,
+        args::Format::List => {
+          print::list::from(
+            colors,
+            print::list::ListConfig {
+              color: color_mode,
+              show,
+              sort,
+            },
+          )
+          .await?
+        }
+        args::Format::Grid => {
+          print::grid::from(
+            colors,
+            print::grid::GridConfig {
+              color: color_mode,
+              show,
+              sort,
+            },
+          )
+          .await?
+        }
+        args::Format::Preview => {
+          print::preview::from(
+            colors,
+            print::preview::PreviewConfig {
+              color: color_mode,
+              simulate: simulate.map(|simulate| match simulate {
+                args::Deficiency::Protan => {
+                  extrapolate::cvd::Deficiency::Protan
+                }
+                args::Deficiency::Deutan => {
+                  extrapolate::cvd::Deficiency::Deutan
+                }
+                args::Deficiency::Tritan => {
+                  extrapolate::cvd::Deficiency::Tritan
+                }
+              }),
+            },
+          )
+          .await?
+        }
+        args::Format::Html => {
+          print::html::from(
+            colors,
+            print::html::HtmlConfig {
+              output,
+              image: generation.image,
+              background,
+            },
+          )
+          .await?
+        }
+        args::Format::Base16 | args::Format::Base24 => {
+          print::base16::from(
+            colors,
+            print::base16::Base16Config {
+              output,
+              name,
+              variant: match format {
+                args::Format::Base24 => print::base16::Variant::Base24,
+                _ => print::base16::Variant::Base16,
+              },
+            },
+          )
+          .await?
+        }
+        args::Format::Gpl
+        | args::Format::Ase
+        | args::Format::Aco
+        | args::Format::PaintNet
+        | args::Format::Kde
+        | args::Format::Soc => {
+          print::export::from(
+            colors,
+            print::export::ExportConfig {
+              output,
+              name,
+              format: export_format(&format).ok_or_else(|| {
+                anyhow::anyhow!("{:?} is not an export format", format)
+              })?,
+            },
+          )
+          .await?
+        }
+        args::Format::Png => {
+          print::png::from(
+            colors,
+            print::png::PngConfig {
+              output,
+              thumbnail: generation.image.filter(|_| thumbnail),
+            },
+          )
+          .await?
+        }
+        args::Format::Svg => {
+          print::svg::from(
+            colors,
+            print::svg::SvgConfig {
+              output,
+              thumbnail: generation.image.filter(|_| thumbnail),
+            },
+          )
+          .await?
+        }
       }
     }
   }
@@ -233,6 +693,154 @@ async fn main() -> anyhow::Result<()> {
   Ok(())
 }
 
+// NOTE: xterm order used by theme files
+const THEME_SLOTS: [config::AnsiSlot; 16] = [
+  config::AnsiSlot::Black,
+  config::AnsiSlot::Red,
+  config::AnsiSlot::Green,
+  config::AnsiSlot::Yellow,
+  config::AnsiSlot::Blue,
+  config::AnsiSlot::Magenta,
+  config::AnsiSlot::Cyan,
+  config::AnsiSlot::White,
+  config::AnsiSlot::BrightBlack,
+  config::AnsiSlot::BrightRed,
+  config::AnsiSlot::BrightGreen,
+  config::AnsiSlot::BrightYellow,
+  config::AnsiSlot::BrightBlue,
+  config::AnsiSlot::BrightMagenta,
+  config::AnsiSlot::BrightCyan,
+  config::AnsiSlot::BrightWhite,
+];
+
+fn colors_to_config(color: &colors::Rgba) -> config::ColorConfig {
+  config::ColorConfig::Rgb {
+    red: color.red,
+    green: color.green,
+    blue: color.blue,
+  }
+}
+
+fn export_format(format: &args::Format) -> Option<print::export::ExportFormat> {
+  match format {
+    args::Format::Gpl => Some(print::export::ExportFormat::Gpl),
+    args::Format::Ase => Some(print::export::ExportFormat::Ase),
+    args::Format::Aco => Some(print::export::ExportFormat::Aco),
+    args::Format::PaintNet => Some(print::export::ExportFormat::PaintNet),
+    args::Format::Kde => Some(print::export::ExportFormat::Kde),
+    args::Format::Soc => Some(print::export::ExportFormat::Soc),
+    args::Format::List
+    | args::Format::Grid
+    | args::Format::Preview
+    | args::Format::Html
+    | args::Format::Png
+    | args::Format::Svg
+    | args::Format::Base16
+    | args::Format::Base24 => None,
+  }
+}
+
+fn scheme_name(path: Option<&String>) -> String {
+  path
+    .and_then(|path| std::path::Path::new(path).file_stem())
+    .map(|stem| stem.to_string_lossy().into_owned())
+    .unwrap_or_else(|| "lulezojne".to_owned())
+}
+
+fn tones_to_plop(tones: extrapolate::tonal::Tones) -> plop::TonalTones {
+  plop::TonalTones {
+    t0: ansi_to_plop(tones.t0),
+    t10: ansi_to_plop(tones.t10),
+    t20: ansi_to_plop(tones.t20),
+    t30: ansi_to_plop(tones.t30),
+    t40: ansi_to_plop(tones.t40),
+    t50: ansi_to_plop(tones.t50),
+    t60: ansi_to_plop(tones.t60),
+    t70: ansi_to_plop(tones.t70),
+    t80: ansi_to_plop(tones.t80),
+    t90: ansi_to_plop(tones.t90),
+    t95: ansi_to_plop(tones.t95),
+    t99: ansi_to_plop(tones.t99),
+    t100: ansi_to_plop(tones.t100),
+  }
+}
+
+fn scheme_to_plop(scheme: extrapolate::tonal::Scheme) -> plop::TonalScheme {
+  plop::TonalScheme {
+    primary: ansi_to_plop(scheme.primary),
+    on_primary: ansi_to_plop(scheme.on_primary),
+    primary_container: ansi_to_plop(scheme.primary_container),
+    on_primary_container: ansi_to_plop(scheme.on_primary_container),
+    secondary: ansi_to_plop(scheme.secondary),
+    on_secondary: ansi_to_plop(scheme.on_secondary),
+    secondary_container: ansi_to_plop(scheme.secondary_container),
+    on_secondary_container: ansi_to_plop(scheme.on_secondary_container),
+    tertiary: ansi_to_plop(scheme.tertiary),
+    on_tertiary: ansi_to_plop(scheme.on_tertiary),
+    tertiary_container: ansi_to_plop(scheme.tertiary_container),
+    on_tertiary_container: ansi_to_plop(scheme.on_tertiary_container),
+    error: ansi_to_plop(scheme.error),
+    on_error: ansi_to_plop(scheme.on_error),
+    error_container: ansi_to_plop(scheme.error_container),
+    on_error_container: ansi_to_plop(scheme.on_error_container),
+    background: ansi_to_plop(scheme.background),
+    on_background: ansi_to_plop(scheme.on_background),
+    surface: ansi_to_plop(scheme.surface),
+    on_surface: ansi_to_plop(scheme.on_surface),
+    surface_variant: ansi_to_plop(scheme.surface_variant),
+    on_surface_variant: ansi_to_plop(scheme.on_surface_variant),
+    outline: ansi_to_plop(scheme.outline),
+    outline_variant: ansi_to_plop(scheme.outline_variant),
+    shadow: ansi_to_plop(scheme.shadow),
+    scrim: ansi_to_plop(scheme.scrim),
+    inverse_surface: ansi_to_plop(scheme.inverse_surface),
+    inverse_on_surface: ansi_to_plop(scheme.inverse_on_surface),
+    inverse_primary: ansi_to_plop(scheme.inverse_primary),
+  }
+}
+
+fn main_to_plop(main: extrapolate::ansi::ResultMain) -> plop::AnsiMain {
+  plop::AnsiMain {
+    black: ansi_to_plop(main.black),
+    red: ansi_to_plop(main.red),
+    green: ansi_to_plop(main.green),
+    blue: ansi_to_plop(main.blue),
+    cyan: ansi_to_plop(main.cyan),
+    yellow: ansi_to_plop(main.yellow),
+    magenta: ansi_to_plop(main.magenta),
+    white: ansi_to_plop(main.white),
+    bright_black: ansi_to_plop(main.bright_black),
+    bright_red: ansi_to_plop(main.bright_red),
+    bright_green: ansi_to_plop(main.bright_green),
+    bright_blue: ansi_to_plop(main.bright_blue),
+    bright_cyan: ansi_to_plop(main.bright_cyan),
+    bright_yellow: ansi_to_plop(main.bright_yellow),
+    bright_magenta: ansi_to_plop(main.bright_magenta),
+    bright_white: ansi_to_plop(main.bright_white),
+  }
+}
+
+fn main_to_print(main: extrapolate::ansi::ResultMain) -> print::AnsiMain {
+  print::AnsiMain {
+    black: ansi_to_print(main.black),
+    red: ansi_to_print(main.red),
+    green: ansi_to_print(main.green),
+    blue: ansi_to_print(main.blue),
+    cyan: ansi_to_print(main.cyan),
+    yellow: ansi_to_print(main.yellow),
+    magenta: ansi_to_print(main.magenta),
+    white: ansi_to_print(main.white),
+    bright_black: ansi_to_print(main.bright_black),
+    bright_red: ansi_to_print(main.bright_red),
+    bright_green: ansi_to_print(main.bright_green),
+    bright_blue: ansi_to_print(main.bright_blue),
+    bright_cyan: ansi_to_print(main.bright_cyan),
+    bright_yellow: ansi_to_print(main.bright_yellow),
+    bright_magenta: ansi_to_print(main.bright_magenta),
+    bright_white: ansi_to_print(main.bright_white),
+  }
+}
+
 fn ansi_to_plop(color: extrapolate::ansi::Rgba) -> plop::Rgba {
   let extrapolate::ansi::Rgba {
     red,
@@ -264,3 +872,107 @@ fn ansi_to_print(color: extrapolate::ansi::Rgba) -> print::Rgba {
     alpha,
   }
 }
+
+fn colors_to_print(color: colors::Rgba) -> print::Rgba {
+  let colors::Rgba {
+    red,
+    green,
+    blue,
+    alpha,
+  } = color;
+
+  print::Rgba {
+    red,
+    green,
+    blue,
+    alpha,
+  }
+}
+
+fn slot_to_ansi(slot: config::AnsiSlot) -> extrapolate::ansi::Slot {
+  match slot {
+    config::AnsiSlot::Black => extrapolate::ansi::Slot::Black,
+    config::AnsiSlot::Red => extrapolate::ansi::Slot::Red,
+    config::AnsiSlot::Green => extrapolate::ansi::Slot::Green,
+    config::AnsiSlot::Blue => extrapolate::ansi::Slot::Blue,
+    config::AnsiSlot::Cyan => extrapolate::ansi::Slot::Cyan,
+    config::AnsiSlot::Yellow => extrapolate::ansi::Slot::Yellow,
+    config::AnsiSlot::Magenta => extrapolate::ansi::Slot::Magenta,
+    config::AnsiSlot::White => extrapolate::ansi::Slot::White,
+    config::AnsiSlot::BrightBlack => extrapolate::ansi::Slot::BrightBlack,
+    config::AnsiSlot::BrightRed => extrapolate::ansi::Slot::BrightRed,
+    config::AnsiSlot::BrightGreen => extrapolate::ansi::Slot::BrightGreen,
+    config::AnsiSlot::BrightBlue => extrapolate::ansi::Slot::BrightBlue,
+    config::AnsiSlot::BrightCyan => extrapolate::ansi::Slot::BrightCyan,
+    config::AnsiSlot::BrightYellow => extrapolate::ansi::Slot::BrightYellow,
+    config::AnsiSlot::BrightMagenta => extrapolate::ansi::Slot::BrightMagenta,
+    config::AnsiSlot::BrightWhite => extrapolate::ansi::Slot::BrightWhite,
+  }
+}
+
+fn role_to_semantic(role: config::SemanticRole) -> extrapolate::semantic::Role {
+  match role {
+    config::SemanticRole::Background => extrapolate::semantic::Role::Background,
+    config::SemanticRole::Foreground => extrapolate::semantic::Role::Foreground,
+    config::SemanticRole::Cursor => extrapolate::semantic::Role::Cursor,
+    config::SemanticRole::CursorText => extrapolate::semantic::Role::CursorText,
+    config::SemanticRole::SelectionBackground => {
+      extrapolate::semantic::Role::SelectionBackground
+    }
+    config::SemanticRole::SelectionForeground => {
+      extrapolate::semantic::Role::SelectionForeground
+    }
+    config::SemanticRole::Url => extrapolate::semantic::Role::Url,
+    config::SemanticRole::Accent => extrapolate::semantic::Role::Accent,
+    config::SemanticRole::Surface => extrapolate::semantic::Role::Surface,
+    config::SemanticRole::Border => extrapolate::semantic::Role::Border,
+    config::SemanticRole::Muted => extrapolate::semantic::Role::Muted,
+    config::SemanticRole::Error => extrapolate::semantic::Role::Error,
+    config::SemanticRole::Warning => extrapolate::semantic::Role::Warning,
+    config::SemanticRole::Success => extrapolate::semantic::Role::Success,
+    config::SemanticRole::Info => extrapolate::semantic::Role::Info,
+  }
+}
+
+fn color_to_reference(
+  color: config::ColorConfig,
+) -> extrapolate::ansi::Reference {
+  match color {
+    config::ColorConfig::Rgb { red, green, blue } => {
+      extrapolate::ansi::Reference::Rgb { red, green, blue }
+    }
+    config::ColorConfig::Oklch {
+      lightness,
+      chroma,
+      hue,
+    } => extrapolate::ansi::Reference::Oklch {
+      lightness,
+      chroma,
+      hue,
+    },
+  }
+}
+
+fn area_metric(area: &config::AnsiAreaConfig) -> extrapolate::ansi::Metric {
+  match area.metric {
+    config::AnsiMetricConfig::Hyab => extrapolate::ansi::Metric::Hyab,
+    config::AnsiMetricConfig::Ciede2000 => extrapolate::ansi::Metric::Ciede2000,
+    config::AnsiMetricConfig::Cie76 => extrapolate::ansi::Metric::Cie76,
+    config::AnsiMetricConfig::Oklab => extrapolate::ansi::Metric::Oklab,
+    config::AnsiMetricConfig::HueWeighted => {
+      extrapolate::ansi::Metric::HueWeighted {
+        hue_weight: area.hue_weight,
+      }
+    }
+  }
+}
+
+fn area_space(area: &config::AnsiAreaConfig) -> extrapolate::ansi::Space {
+  match area.space {
+    config::AnsiSpaceConfig::Okhsl => extrapolate::ansi::Space::Okhsl,
+    config::AnsiSpaceConfig::Okhsv => extrapolate::ansi::Space::Okhsv,
+    config::AnsiSpaceConfig::Oklch => extrapolate::ansi::Space::Oklch,
+    config::AnsiSpaceConfig::Hsluv => extrapolate::ansi::Space::Hsluv,
+    config::AnsiSpaceConfig::Lab => extrapolate::ansi::Space::Lab,
+  }
+}
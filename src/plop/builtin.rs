@@ -0,0 +1,31 @@
+// NOTE: templates embedded in the binary so configs can use builtin:kitty
+
+pub const PREFIX: &str = "builtin:";
+
+pub fn get(name: &str) -> Option<&'static str> {
+  Some(match name {
+    "kitty" => include_str!("../../assets/templates/kitty.conf"),
+    "alacritty" => include_str!("../../assets/templates/alacritty.toml"),
+    "foot" => include_str!("../../assets/templates/foot.ini"),
+    "wezterm" => include_str!("../../assets/templates/wezterm.lua"),
+    "ghostty" => include_str!("../../assets/templates/ghostty"),
+    "xresources" => include_str!("../../assets/templates/Xresources"),
+    "st" => include_str!("../../assets/templates/st.h"),
+    "konsole" => include_str!("../../assets/templates/konsole.colorscheme"),
+    "windows-terminal" => {
+      include_str!("../../assets/templates/windows-terminal.json")
+    }
+    "hyprland" => include_str!("../../assets/templates/hyprland.conf"),
+    "sway" | "i3" => include_str!("../../assets/templates/sway"),
+    "bspwm" => include_str!("../../assets/templates/bspwmrc"),
+    "waybar" => include_str!("../../assets/templates/waybar.css"),
+    "polybar" => include_str!("../../assets/templates/polybar.ini"),
+    "eww" => include_str!("../../assets/templates/eww.scss"),
+    "dunst" => include_str!("../../assets/templates/dunstrc"),
+    "mako" => include_str!("../../assets/templates/mako"),
+    "rofi" => include_str!("../../assets/templates/rofi.rasi"),
+    "wofi" => include_str!("../../assets/templates/wofi.css"),
+    "swaylock" => include_str!("../../assets/templates/swaylock"),
+    _ => return None,
+  })
+}
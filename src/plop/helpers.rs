@@ -145,6 +145,55 @@ handlebars_helper!(SetSaturationHelper: |color: Rgba, saturation: f32| {
   })
 });
 
+handlebars_helper!(MixHelper: |lhs: Rgba, rhs: Rgba, factor: f32| {
+  json!(mix(&lhs, &rhs, factor))
+});
+
+// NOTE: steps include both ends so a ramp of 2 is just the two colors
+// NOTE: steps are capped so a typo can't allocate without bound
+const MAX_RAMP_STEPS: u64 = 256;
+
+handlebars_helper!(RampHelper: |lhs: Rgba, rhs: Rgba, steps: u64| {
+  let ramp = match steps.min(MAX_RAMP_STEPS) {
+    0 => Vec::new(),
+    1 => vec![lhs],
+    steps => (0..steps)
+      .map(|step| mix(&lhs, &rhs, step as f32 / (steps - 1) as f32))
+      .collect(),
+  };
+  json!(ramp)
+});
+
+fn mix(lhs: &Rgba, rhs: &Rgba, factor: f32) -> Rgba {
+  let oklab = |color: &Rgba| {
+    palette::IntoColor::<palette::Oklaba>::into_color(
+      palette::Alpha::<palette::LinSrgb<u8>, f32>::new(
+        color.red,
+        color.green,
+        color.blue,
+        color.alpha,
+      )
+      .into_format::<f32, f32>(),
+    )
+  };
+  let lhs = oklab(lhs);
+  let rhs = oklab(rhs);
+  let mixed = crate::extrapolate::mix(lhs, rhs, factor);
+  let palette::Alpha::<palette::LinSrgb<u8>, f32> {
+    color: palette::LinSrgb::<u8> {
+      red, green, blue, ..
+    },
+    alpha,
+  } = palette::IntoColor::<palette::LinSrgba>::into_color(mixed)
+    .into_format::<u8, f32>();
+  Rgba {
+    red,
+    green,
+    blue,
+    alpha,
+  }
+}
+
 pub fn register(handlebars: &mut handlebars::Handlebars) {
   handlebars.register_helper("hex", Box::new(HexHelper));
   handlebars.register_helper("hexa", Box::new(HexaHelper));
@@ -156,4 +205,6 @@ pub fn register(handlebars: &mut handlebars::Handlebars) {
   handlebars.register_helper("set-alpha", Box::new(SetAlphaHelper));
   handlebars.register_helper("set-lightness", Box::new(SetLightnessHelper));
   handlebars.register_helper("set-saturation", Box::new(SetSaturationHelper));
+  handlebars.register_helper("mix", Box::new(MixHelper));
+  handlebars.register_helper("ramp", Box::new(RampHelper));
 }
@@ -1,5 +1,8 @@
+mod builtin;
 mod helpers;
 
+use std::collections::BTreeMap;
+
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
@@ -7,14 +10,33 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Context {
+  pub mode: Mode,
   pub ansi: Ansi,
+  pub semantic: Semantic,
+  pub base16: Base16,
+  pub tonal: Tonal,
+
+  // NOTE: colors named in the config so templates can use named.rosewater
+  pub named: BTreeMap<String, Rgba>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+  Dark,
+  Light,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ansi {
   pub main: AnsiMain,
+  pub dim: AnsiMain,
   pub gradient: Vec<Rgba>,
   pub grayscale: Vec<Rgba>,
+
+  // NOTE: all 256 xterm colors keyed color0 to color255
+  // NOTE: so templates can use ansi.indexed.color0
+  pub indexed: BTreeMap<String, Rgba>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +59,129 @@ pub struct AnsiMain {
   pub bright_white: Rgba,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Semantic {
+  pub background: Rgba,
+  pub foreground: Rgba,
+  pub cursor: Rgba,
+  pub cursor_text: Rgba,
+  pub selection_background: Rgba,
+  pub selection_foreground: Rgba,
+  pub url: Rgba,
+  pub accent: Rgba,
+  pub surface: Rgba,
+  pub border: Rgba,
+  pub muted: Rgba,
+  pub error: Rgba,
+  pub warning: Rgba,
+  pub success: Rgba,
+  pub info: Rgba,
+}
+
+// NOTE: base10 to base17 are the base24 extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Base16 {
+  pub base00: Rgba,
+  pub base01: Rgba,
+  pub base02: Rgba,
+  pub base03: Rgba,
+  pub base04: Rgba,
+  pub base05: Rgba,
+  pub base06: Rgba,
+  pub base07: Rgba,
+  pub base08: Rgba,
+  pub base09: Rgba,
+  #[serde(rename = "base0A")]
+  pub base0a: Rgba,
+  #[serde(rename = "base0B")]
+  pub base0b: Rgba,
+  #[serde(rename = "base0C")]
+  pub base0c: Rgba,
+  #[serde(rename = "base0D")]
+  pub base0d: Rgba,
+  #[serde(rename = "base0E")]
+  pub base0e: Rgba,
+  #[serde(rename = "base0F")]
+  pub base0f: Rgba,
+  pub base10: Rgba,
+  pub base11: Rgba,
+  pub base12: Rgba,
+  pub base13: Rgba,
+  pub base14: Rgba,
+  pub base15: Rgba,
+  pub base16: Rgba,
+  pub base17: Rgba,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tonal {
+  pub seed: Rgba,
+  pub palettes: TonalPalettes,
+  pub light: TonalScheme,
+  pub dark: TonalScheme,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonalPalettes {
+  pub primary: TonalTones,
+  pub secondary: TonalTones,
+  pub tertiary: TonalTones,
+  pub neutral: TonalTones,
+  pub neutral_variant: TonalTones,
+  pub error: TonalTones,
+}
+
+// NOTE: tones 0 to 100 as t0, t10 and so on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonalTones {
+  pub t0: Rgba,
+  pub t10: Rgba,
+  pub t20: Rgba,
+  pub t30: Rgba,
+  pub t40: Rgba,
+  pub t50: Rgba,
+  pub t60: Rgba,
+  pub t70: Rgba,
+  pub t80: Rgba,
+  pub t90: Rgba,
+  pub t95: Rgba,
+  pub t99: Rgba,
+  pub t100: Rgba,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonalScheme {
+  pub primary: Rgba,
+  pub on_primary: Rgba,
+  pub primary_container: Rgba,
+  pub on_primary_container: Rgba,
+  pub secondary: Rgba,
+  pub on_secondary: Rgba,
+  pub secondary_container: Rgba,
+  pub on_secondary_container: Rgba,
+  pub tertiary: Rgba,
+  pub on_tertiary: Rgba,
+  pub tertiary_container: Rgba,
+  pub on_tertiary_container: Rgba,
+  pub error: Rgba,
+  pub on_error: Rgba,
+  pub error_container: Rgba,
+  pub on_error_container: Rgba,
+  pub background: Rgba,
+  pub on_background: Rgba,
+  pub surface: Rgba,
+  pub on_surface: Rgba,
+  pub surface_variant: Rgba,
+  pub on_surface_variant: Rgba,
+  pub outline: Rgba,
+  pub outline_variant: Rgba,
+  pub shadow: Rgba,
+  pub scrim: Rgba,
+  pub inverse_surface: Rgba,
+  pub inverse_on_surface: Rgba,
+  pub inverse_primary: Rgba,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Rgba {
   pub red: u8,
@@ -69,7 +214,16 @@ pub async fn many(context: Context, config: Config) -> anyhow::Result<()> {
     let mut definitions = Vec::new();
     for definition in config.definitions {
       definitions.push(Definition {
-        template_or_path: if is_path(definition.template_or_path.as_str()) {
+        template_or_path: if let Some(name) =
+          definition.template_or_path.strip_prefix(builtin::PREFIX)
+        {
+          match builtin::get(name) {
+            Some(template) => template.to_owned(),
+            None => {
+              return Err(anyhow::anyhow!("Unknown builtin template {}", name))
+            }
+          }
+        } else if is_path(definition.template_or_path.as_str()) {
           expand(definition.template_or_path.clone())?
         } else {
           definition.template_or_path
@@ -0,0 +1,39 @@
+#[derive(Debug, Clone)]
+pub struct Base16Config {
+  pub output: Option<String>,
+  pub name: String,
+  pub variant: Variant,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Variant {
+  Base16,
+  Base24,
+}
+
+#[tracing::instrument(skip(colors))]
+pub async fn from(
+  colors: super::Colors,
+  config: Base16Config,
+) -> anyhow::Result<()> {
+  let count = match config.variant {
+    Variant::Base16 => 16,
+    Variant::Base24 => 24,
+  };
+
+  let mut yaml = String::new();
+  yaml.push_str(format!("scheme: \"{}\"\n", escape(&config.name)).as_str());
+  yaml.push_str("author: \"lulezojne\"\n");
+  for (name, color) in colors.base16.iter().take(count) {
+    // NOTE: scheme files use hex without the leading hash
+    yaml.push_str(
+      format!("{name}: \"{}\"\n", color.hex().trim_start_matches('#')).as_str(),
+    );
+  }
+
+  super::write(config.output, yaml.into_bytes()).await
+}
+
+fn escape(text: &str) -> String {
+  text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,315 @@
+// NOTE: palette files for graphics tools with main, semantic and source colors
+
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+  pub output: Option<String>,
+  pub name: String,
+  pub format: ExportFormat,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+  Gpl,
+  Ase,
+  Aco,
+  PaintNet,
+  Kde,
+  Soc,
+}
+
+// NOTE: Paint.NET ignores anything past this
+const PAINT_NET_MAX: usize = 96;
+
+#[tracing::instrument(skip(colors))]
+pub async fn from(
+  colors: super::Colors,
+  config: ExportConfig,
+) -> anyhow::Result<()> {
+  let mut sources = colors.source.clone();
+  sources.sort_by(|lhs, rhs| rhs.weight.total_cmp(&lhs.weight));
+
+  let entries = colors
+    .ansi
+    .main
+    .iter()
+    .map(|(name, color)| (name.to_owned(), color))
+    .chain(
+      colors
+        .semantic
+        .iter()
+        .map(|(name, color)| (name.to_owned(), color)),
+    )
+    .chain(
+      sources
+        .iter()
+        .enumerate()
+        .map(|(index, source)| (format!("source{index}"), &source.color)),
+    )
+    .collect::<Vec<_>>();
+
+  let bytes = match config.format {
+    ExportFormat::Gpl => gpl(&config.name, &entries).into_bytes(),
+    ExportFormat::Ase => ase(&entries),
+    ExportFormat::Aco => aco(&entries),
+    ExportFormat::PaintNet => paint_net(&config.name, &entries).into_bytes(),
+    ExportFormat::Kde => kde(&config.name, &entries).into_bytes(),
+    ExportFormat::Soc => soc(&entries).into_bytes(),
+  };
+
+  super::write(config.output, bytes).await
+}
+
+fn gpl(name: &str, entries: &[(String, &super::Rgba)]) -> String {
+  let mut gpl = format!("GIMP Palette\nName: {name}\nColumns: 8\n#\n");
+  for (name, color) in entries {
+    let super::Rgba {
+      red, green, blue, ..
+    } = color;
+    gpl.push_str(format!("{red:3} {green:3} {blue:3}\t{name}\n").as_str());
+  }
+
+  gpl
+}
+
+fn kde(name: &str, entries: &[(String, &super::Rgba)]) -> String {
+  let mut kde = format!("KDE RGB Palette\n#{name}\n");
+  for (name, color) in entries {
+    let super::Rgba {
+      red, green, blue, ..
+    } = color;
+    kde.push_str(format!("{red} {green} {blue}\t{name}\n").as_str());
+  }
+
+  kde
+}
+
+fn paint_net(name: &str, entries: &[(String, &super::Rgba)]) -> String {
+  let mut paint_net = format!("; paint.net Palette File\n; {name}\n");
+  for (_, color) in entries.iter().take(PAINT_NET_MAX) {
+    let super::Rgba {
+      red,
+      green,
+      blue,
+      alpha,
+    } = color;
+    let alpha = (alpha * 255.0f32).round() as u8;
+    paint_net.push_str(
+      format!("{alpha:02X}{red:02X}{green:02X}{blue:02X}\n").as_str(),
+    );
+  }
+
+  paint_net
+}
+
+fn soc(entries: &[(String, &super::Rgba)]) -> String {
+  let mut soc = String::from(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+    <ooo:color-table \
+    xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\" \
+    xmlns:draw=\"urn:oasis:names:tc:opendocument:xmlns:drawing:1.0\" \
+    xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+    xmlns:svg=\"http://www.w3.org/2000/svg\" \
+    xmlns:ooo=\"http://openoffice.org/2004/office\">\n",
+  );
+  for (name, color) in entries {
+    soc.push_str(
+      format!(
+        "<draw:color draw:name=\"{name}\" draw:color=\"{}\"/>\n",
+        color.hex().to_lowercase()
+      )
+      .as_str(),
+    );
+  }
+  soc.push_str("</ooo:color-table>\n");
+
+  soc
+}
+
+// NOTE: Adobe Swatch Exchange with big endian blocks of RGB floats
+fn ase(entries: &[(String, &super::Rgba)]) -> Vec<u8> {
+  let mut ase = Vec::new();
+  ase.extend_from_slice(b"ASEF");
+  ase.extend_from_slice(&1u16.to_be_bytes());
+  ase.extend_from_slice(&0u16.to_be_bytes());
+  ase.extend_from_slice(&(entries.len() as u32).to_be_bytes());
+
+  for (name, color) in entries {
+    let name = utf16(name);
+    let mut block = Vec::new();
+    block.extend_from_slice(&((name.len() / 2) as u16).to_be_bytes());
+    block.extend_from_slice(&name);
+    block.extend_from_slice(b"RGB ");
+    for channel in [color.red, color.green, color.blue] {
+      block.extend_from_slice(
+        &(Into::<f32>::into(channel) / 255.0f32).to_be_bytes(),
+      );
+    }
+    block.extend_from_slice(&2u16.to_be_bytes()); // NOTE: normal color
+
+    ase.extend_from_slice(&1u16.to_be_bytes()); // NOTE: color entry
+    ase.extend_from_slice(&(block.len() as u32).to_be_bytes());
+    ase.extend_from_slice(&block);
+  }
+
+  ase
+}
+
+// NOTE: Photoshop swatches with an unnamed version 1 and named version 2
+fn aco(entries: &[(String, &super::Rgba)]) -> Vec<u8> {
+  let mut aco = Vec::new();
+  for version in [1u16, 2u16] {
+    aco.extend_from_slice(&version.to_be_bytes());
+    aco.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+    for (name, color) in entries {
+      aco.extend_from_slice(&0u16.to_be_bytes()); // NOTE: RGB color space
+      for channel in [color.red, color.green, color.blue] {
+        aco.extend_from_slice(&(u16::from(channel) * 257).to_be_bytes());
+      }
+      aco.extend_from_slice(&0u16.to_be_bytes());
+
+      if version == 2 {
+        let name = utf16(name);
+        aco.extend_from_slice(&((name.len() / 2) as u32).to_be_bytes());
+        aco.extend_from_slice(&name);
+      }
+    }
+  }
+
+  aco
+}
+
+// NOTE: big endian UTF-16 with a null terminator
+fn utf16(text: &str) -> Vec<u8> {
+  text
+    .encode_utf16()
+    .chain(std::iter::once(0u16))
+    .flat_map(|unit| unit.to_be_bytes())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entries() -> Vec<(String, super::super::Rgba)> {
+    vec![
+      (
+        "red".to_owned(),
+        super::super::Rgba {
+          red: 204,
+          green: 36,
+          blue: 29,
+          alpha: 1.0f32,
+        },
+      ),
+      (
+        "bright_blue".to_owned(),
+        super::super::Rgba {
+          red: 131,
+          green: 165,
+          blue: 152,
+          alpha: 1.0f32,
+        },
+      ),
+    ]
+  }
+
+  fn borrowed(
+    entries: &[(String, super::super::Rgba)],
+  ) -> Vec<(String, &super::super::Rgba)> {
+    entries
+      .iter()
+      .map(|(name, color)| (name.clone(), color))
+      .collect()
+  }
+
+  fn u16_at(bytes: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([bytes[at], bytes[at + 1]])
+  }
+
+  fn u32_at(bytes: &[u8], at: usize) -> u32 {
+    u32::from_be_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+  }
+
+  #[test]
+  fn ase_blocks() {
+    let entries = entries();
+    let ase = ase(&borrowed(&entries));
+    assert_eq!(&ase[0..4], b"ASEF");
+    assert_eq!(u16_at(&ase, 4), 1);
+    assert_eq!(u16_at(&ase, 6), 0);
+    assert_eq!(u32_at(&ase, 8), entries.len() as u32);
+
+    let mut at = 12;
+    for (name, color) in &entries {
+      assert_eq!(u16_at(&ase, at), 1);
+      let length = u32_at(&ase, at + 2) as usize;
+      let block = &ase[at + 6..at + 6 + length];
+
+      let name_length = u16_at(block, 0) as usize;
+      assert_eq!(name_length, name.len() + 1);
+      let name_end = 2 + name_length * 2;
+      assert_eq!(&block[name_end..name_end + 4], b"RGB ");
+      let red = f32::from_be_bytes([
+        block[name_end + 4],
+        block[name_end + 5],
+        block[name_end + 6],
+        block[name_end + 7],
+      ]);
+      assert_eq!((red * 255.0f32).round() as u8, color.red);
+      assert_eq!(length, name_end + 4 + 12 + 2);
+
+      at += 6 + length;
+    }
+    assert_eq!(at, ase.len());
+  }
+
+  #[test]
+  fn aco_blocks() {
+    let entries = entries();
+    let aco = aco(&borrowed(&entries));
+
+    let mut at = 0;
+    for version in [1u16, 2u16] {
+      assert_eq!(u16_at(&aco, at), version);
+      assert_eq!(u16_at(&aco, at + 2), entries.len() as u16);
+      at += 4;
+      for (name, color) in &entries {
+        assert_eq!(u16_at(&aco, at), 0);
+        assert_eq!(u16_at(&aco, at + 2), u16::from(color.red) * 257);
+        assert_eq!(u16_at(&aco, at + 4), u16::from(color.green) * 257);
+        assert_eq!(u16_at(&aco, at + 6), u16::from(color.blue) * 257);
+        at += 10;
+        if version == 2 {
+          let name_length = u32_at(&aco, at) as usize;
+          assert_eq!(name_length, name.len() + 1);
+          at += 4 + name_length * 2;
+        }
+      }
+    }
+    assert_eq!(at, aco.len());
+  }
+
+  #[tokio::test]
+  async fn gpl_round_trip() -> anyhow::Result<()> {
+    let entries = entries();
+    let path = std::env::temp_dir()
+      .join(format!("lulezojne-export-{}.gpl", std::process::id()));
+    tokio::fs::write(&path, gpl("test", &borrowed(&entries))).await?;
+
+    let colors =
+      crate::colors::explicit::from_file(path.to_string_lossy().into_owned())
+        .await;
+    tokio::fs::remove_file(&path).await?;
+
+    let colors = colors?;
+    assert_eq!(colors.palette.len(), entries.len());
+    for (color, (_, expected)) in colors.palette.iter().zip(entries.iter()) {
+      assert_eq!(
+        (color.red, color.green, color.blue),
+        (expected.red, expected.green, expected.blue)
+      );
+    }
+    Ok(())
+  }
+}
@@ -1,35 +1,71 @@
 use std::io::Write;
 
-use colored::Colorize;
+#[derive(Debug, Clone)]
+pub struct GridConfig {
+  pub color: super::ColorMode,
+  pub show: Vec<super::Show>,
+  pub sort: super::Sort,
+}
 
 #[tracing::instrument(skip_all)]
-pub async fn from(colors: super::Colors) -> anyhow::Result<()> {
+pub async fn from(
+  colors: super::Colors,
+  config: GridConfig,
+) -> anyhow::Result<()> {
+  let painter = super::term::Painter::new(config.color);
+
+  if config.show.contains(&super::Show::Source) {
+    std::io::stdout().write_all("SOURCE:\n".as_bytes())?;
+    for (source, picks) in colors.sources(config.sort) {
+      super::source_one(&painter, source, picks)?;
+    }
+    super::pinned_one(colors.pinned())?;
+    std::io::stdout().write_all("\n".as_bytes())?;
+  }
+
+  if config.show.contains(&super::Show::Semantic) {
+    std::io::stdout().write_all("SEMANTIC:\n".as_bytes())?;
+    for (name, color) in colors.semantic.iter() {
+      super::semantic_one(&painter, name, color)?;
+    }
+    std::io::stdout().write_all("\n".as_bytes())?;
+  }
+
+  if !config.show.contains(&super::Show::Ansi) {
+    return Ok(());
+  }
+
   std::io::stdout().write_all("MAIN:\n".as_bytes())?;
-  one(colors.ansi.main.black)?;
-  one(colors.ansi.main.red)?;
-  one(colors.ansi.main.green)?;
-  one(colors.ansi.main.blue)?;
-  one(colors.ansi.main.cyan)?;
-  one(colors.ansi.main.yellow)?;
-  one(colors.ansi.main.magenta)?;
-  one(colors.ansi.main.white)?;
-  one(colors.ansi.main.bright_black)?;
-  one(colors.ansi.main.bright_red)?;
-  one(colors.ansi.main.bright_green)?;
-  one(colors.ansi.main.bright_blue)?;
-  one(colors.ansi.main.bright_cyan)?;
-  one(colors.ansi.main.bright_yellow)?;
-  one(colors.ansi.main.bright_magenta)?;
-  one(colors.ansi.main.bright_white)?;
+  one(&painter, colors.ansi.main.black)?;
+  one(&painter, colors.ansi.main.red)?;
+  one(&painter, colors.ansi.main.green)?;
+  one(&painter, colors.ansi.main.blue)?;
+  one(&painter, colors.ansi.main.cyan)?;
+  one(&painter, colors.ansi.main.yellow)?;
+  one(&painter, colors.ansi.main.magenta)?;
+  one(&painter, colors.ansi.main.white)?;
+  one(&painter, colors.ansi.main.bright_black)?;
+  one(&painter, colors.ansi.main.bright_red)?;
+  one(&painter, colors.ansi.main.bright_green)?;
+  one(&painter, colors.ansi.main.bright_blue)?;
+  one(&painter, colors.ansi.main.bright_cyan)?;
+  one(&painter, colors.ansi.main.bright_yellow)?;
+  one(&painter, colors.ansi.main.bright_magenta)?;
+  one(&painter, colors.ansi.main.bright_white)?;
+
+  std::io::stdout().write_all("\nDIM:\n".as_bytes())?;
+  for (_, color) in colors.ansi.dim.iter() {
+    one(&painter, color.clone())?;
+  }
 
   std::io::stdout().write_all("\nGRADIENT:\n".as_bytes())?;
   for color in colors.ansi.gradient {
-    one(color)?;
+    one(&painter, color)?;
   }
 
   std::io::stdout().write_all("\nGRAYSCALE:\n".as_bytes())?;
   for color in colors.ansi.grayscale {
-    one(color)?;
+    one(&painter, color)?;
   }
 
   std::io::stdout().write_all("\n".as_bytes())?;
@@ -37,18 +73,22 @@ pub async fn from(colors: super::Colors) -> anyhow::Result<()> {
   Ok(())
 }
 
-fn one(color: super::Rgba) -> anyhow::Result<()> {
+fn one(
+  painter: &super::term::Painter,
+  color: super::Rgba,
+) -> anyhow::Result<()> {
   let super::Rgba {
     red: r,
     green: g,
     blue: b,
     alpha: a,
-  } = color;
+  } = color.clone();
   std::io::stdout().write_all(
-    format!("rgba({r}, {g}, {b}, {a})\n")
-      .custom_color(colored::CustomColor { r, g, b })
-      .to_string()
-      .as_bytes(),
+    format!(
+      "{}\n",
+      painter.fg(format!("rgba({r}, {g}, {b}, {a})").as_str(), &color)
+    )
+    .as_bytes(),
   )?;
 
   Ok(())
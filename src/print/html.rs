@@ -0,0 +1,136 @@
+use base64::Engine;
+
+const THUMBNAIL_WIDTH: u32 = 640;
+
+#[derive(Debug, Clone)]
+pub struct HtmlConfig {
+  pub output: Option<String>,
+  pub image: Option<String>,
+  pub background: String,
+}
+
+#[tracing::instrument(skip(colors))]
+pub async fn from(
+  colors: super::Colors,
+  config: HtmlConfig,
+) -> anyhow::Result<()> {
+  let background = colors
+    .ansi
+    .main
+    .iter()
+    .find(|(name, _)| *name == config.background)
+    .map(|(_, color)| color.clone())
+    .ok_or_else(|| {
+      anyhow::anyhow!("Unknown background color {}", config.background)
+    })?;
+  let foreground = background.readable();
+
+  let mut html = String::new();
+  html.push_str(
+    format!(
+      "<!DOCTYPE html>\n\
+      <html>\n\
+      <head>\n\
+      <meta charset=\"utf-8\">\n\
+      <title>lulezojne</title>\n\
+      <style>\n\
+        body {{ background: {}; color: {}; font-family: monospace; margin: 2em; }}\n\
+        h2 {{ margin-top: 2em; }}\n\
+        img {{ max-width: 100%; }}\n\
+        .swatches {{ display: grid; gap: 4px; }}\n\
+        .main {{ grid-template-columns: repeat(8, 1fr); }}\n\
+        .gradient {{ grid-template-columns: repeat(12, 1fr); }}\n\
+        .grayscale {{ grid-template-columns: repeat(12, 1fr); }}\n\
+        .source {{ grid-template-columns: repeat(8, 1fr); }}\n\
+        .semantic {{ grid-template-columns: repeat(5, 1fr); }}\n\
+        .swatch {{ padding: 0.5em; min-height: 4em; font-size: 0.8em; }}\n\
+        .swatch span {{ display: block; }}\n\
+        .name {{ font-weight: bold; }}\n\
+      </style>\n\
+      </head>\n\
+      <body>\n",
+      background.hex(),
+      foreground.hex(),
+    )
+    .as_str(),
+  );
+
+  if let Some(image) = config.image {
+    let thumbnail = super::thumbnail(image, THUMBNAIL_WIDTH).await?;
+    let data = base64::engine::general_purpose::STANDARD
+      .encode(super::encode(&thumbnail)?);
+    html.push_str("<h2>Image</h2>\n");
+    html.push_str(
+      format!("<img src=\"data:image/png;base64,{data}\" alt=\"image\">\n")
+        .as_str(),
+    );
+  }
+
+  let mut source = colors.source.clone();
+  source.sort_by(|lhs, rhs| rhs.weight.total_cmp(&lhs.weight));
+  html.push_str("<h2>Source</h2>\n<div class=\"swatches source\">\n");
+  for super::Source { color, weight } in source.iter() {
+    html.push_str(
+      swatch(None, color, format!("{:.2}%", weight * 100.0f32).as_str())
+        .as_str(),
+    );
+  }
+  html.push_str("</div>\n");
+
+  let contrast = |color: &super::Rgba| {
+    format!(
+      "{:.2}:1 on {}",
+      color.contrast(&background),
+      config.background
+    )
+  };
+
+  html.push_str("<h2>Main</h2>\n<div class=\"swatches main\">\n");
+  for (name, color) in colors.ansi.main.iter() {
+    html.push_str(swatch(Some(name), color, contrast(color).as_str()).as_str());
+  }
+  html.push_str("</div>\n");
+
+  html.push_str("<h2>Dim</h2>\n<div class=\"swatches main\">\n");
+  for (name, color) in colors.ansi.dim.iter() {
+    html.push_str(swatch(Some(name), color, contrast(color).as_str()).as_str());
+  }
+  html.push_str("</div>\n");
+
+  html.push_str("<h2>Semantic</h2>\n<div class=\"swatches semantic\">\n");
+  for (name, color) in colors.semantic.iter() {
+    html.push_str(swatch(Some(name), color, contrast(color).as_str()).as_str());
+  }
+  html.push_str("</div>\n");
+
+  html.push_str("<h2>Gradient</h2>\n<div class=\"swatches gradient\">\n");
+  for color in colors.ansi.gradient.iter() {
+    html.push_str(swatch(None, color, contrast(color).as_str()).as_str());
+  }
+  html.push_str("</div>\n");
+
+  html.push_str("<h2>Grayscale</h2>\n<div class=\"swatches grayscale\">\n");
+  for color in colors.ansi.grayscale.iter() {
+    html.push_str(swatch(None, color, contrast(color).as_str()).as_str());
+  }
+  html.push_str("</div>\n");
+
+  html.push_str("</body>\n</html>\n");
+
+  super::write(config.output, html.into_bytes()).await
+}
+
+fn swatch(name: Option<&str>, color: &super::Rgba, detail: &str) -> String {
+  let name = match name {
+    Some(name) => format!("<span class=\"name\">{name}</span>"),
+    None => String::new(),
+  };
+
+  format!(
+    "<div class=\"swatch\" style=\"background: {}; color: {};\">\
+      {name}<span>{}</span><span>{detail}</span></div>\n",
+    color.hex(),
+    color.readable().hex(),
+    color.hex(),
+  )
+}
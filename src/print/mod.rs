@@ -1,36 +1,136 @@
+mod font;
+mod sheet;
+mod term;
+
+pub mod base16;
+pub mod export;
 pub mod grid;
+pub mod html;
 pub mod list;
+pub mod png;
+pub mod preview;
+pub mod svg;
+
+use std::io::Write;
 
 #[derive(Debug, Clone)]
 pub struct Colors {
+  pub source: Vec<Source>,
   pub ansi: Ansi,
+  pub semantic: Semantic,
+  pub base16: Base16,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ColorMode {
+  Always,
+  Never,
+  #[default]
+  Auto,
+  Indexed,
+  Basic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Show {
+  Ansi,
+  Source,
+  Semantic,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Sort {
+  #[default]
+  Weight,
+  Hue,
+}
+
+#[derive(Debug, Clone)]
+pub struct Source {
+  pub color: Rgba,
+  pub weight: f32,
 }
 
 #[derive(Debug, Clone)]
 pub struct Ansi {
   pub main: AnsiMain,
+  pub dim: AnsiMain,
   pub gradient: Vec<Rgba>,
   pub grayscale: Vec<Rgba>,
+
+  // NOTE: index of the source color each main color was mixed from
+  pub picks: AnsiMain<Option<usize>>,
+
+  // NOTE: main colors fixed by overrides instead of mixed from a source
+  pub pinned: AnsiMain<bool>,
 }
 
 #[derive(Debug, Clone)]
-pub struct AnsiMain {
-  pub black: Rgba,
-  pub red: Rgba,
-  pub green: Rgba,
-  pub blue: Rgba,
-  pub cyan: Rgba,
-  pub yellow: Rgba,
-  pub magenta: Rgba,
-  pub white: Rgba,
-  pub bright_black: Rgba,
-  pub bright_red: Rgba,
-  pub bright_green: Rgba,
-  pub bright_blue: Rgba,
-  pub bright_cyan: Rgba,
-  pub bright_yellow: Rgba,
-  pub bright_magenta: Rgba,
-  pub bright_white: Rgba,
+pub struct AnsiMain<T = Rgba> {
+  pub black: T,
+  pub red: T,
+  pub green: T,
+  pub blue: T,
+  pub cyan: T,
+  pub yellow: T,
+  pub magenta: T,
+  pub white: T,
+  pub bright_black: T,
+  pub bright_red: T,
+  pub bright_green: T,
+  pub bright_blue: T,
+  pub bright_cyan: T,
+  pub bright_yellow: T,
+  pub bright_magenta: T,
+  pub bright_white: T,
+}
+
+#[derive(Debug, Clone)]
+pub struct Semantic {
+  pub background: Rgba,
+  pub foreground: Rgba,
+  pub cursor: Rgba,
+  pub cursor_text: Rgba,
+  pub selection_background: Rgba,
+  pub selection_foreground: Rgba,
+  pub url: Rgba,
+  pub accent: Rgba,
+  pub surface: Rgba,
+  pub border: Rgba,
+  pub muted: Rgba,
+  pub error: Rgba,
+  pub warning: Rgba,
+  pub success: Rgba,
+  pub info: Rgba,
+}
+
+// NOTE: base10 to base17 are the base24 extension
+#[derive(Debug, Clone)]
+pub struct Base16 {
+  pub base00: Rgba,
+  pub base01: Rgba,
+  pub base02: Rgba,
+  pub base03: Rgba,
+  pub base04: Rgba,
+  pub base05: Rgba,
+  pub base06: Rgba,
+  pub base07: Rgba,
+  pub base08: Rgba,
+  pub base09: Rgba,
+  pub base0a: Rgba,
+  pub base0b: Rgba,
+  pub base0c: Rgba,
+  pub base0d: Rgba,
+  pub base0e: Rgba,
+  pub base0f: Rgba,
+  pub base10: Rgba,
+  pub base11: Rgba,
+  pub base12: Rgba,
+  pub base13: Rgba,
+  pub base14: Rgba,
+  pub base15: Rgba,
+  pub base16: Rgba,
+  pub base17: Rgba,
 }
 
 #[derive(Debug, Clone)]
@@ -40,3 +140,302 @@ pub struct Rgba {
   pub blue: u8,
   pub alpha: f32,
 }
+
+impl Colors {
+  // NOTE: source colors with names of main colors that were mixed from them
+  pub fn sources(&self, sort: Sort) -> Vec<(&Source, Vec<&'static str>)> {
+    let mut sources = self
+      .source
+      .iter()
+      .enumerate()
+      .map(|(index, source)| {
+        (
+          source,
+          self
+            .ansi
+            .picks
+            .iter()
+            .filter(|(_, pick)| **pick == Some(index))
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    match sort {
+      Sort::Weight => {
+        sources.sort_by(|(lhs, _), (rhs, _)| rhs.weight.total_cmp(&lhs.weight))
+      }
+      Sort::Hue => sources.sort_by(|(lhs, _), (rhs, _)| {
+        lhs.color.hue().total_cmp(&rhs.color.hue())
+      }),
+    }
+
+    sources
+  }
+
+  pub fn pinned(&self) -> Vec<&'static str> {
+    self
+      .ansi
+      .pinned
+      .iter()
+      .filter(|(_, pinned)| **pinned)
+      .map(|(name, _)| name)
+      .collect()
+  }
+}
+
+impl Semantic {
+  pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Rgba)> {
+    [
+      ("background", &self.background),
+      ("foreground", &self.foreground),
+      ("cursor", &self.cursor),
+      ("cursor_text", &self.cursor_text),
+      ("selection_background", &self.selection_background),
+      ("selection_foreground", &self.selection_foreground),
+      ("url", &self.url),
+      ("accent", &self.accent),
+      ("surface", &self.surface),
+      ("border", &self.border),
+      ("muted", &self.muted),
+      ("error", &self.error),
+      ("warning", &self.warning),
+      ("success", &self.success),
+      ("info", &self.info),
+    ]
+    .into_iter()
+  }
+}
+
+impl Base16 {
+  pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Rgba)> {
+    [
+      ("base00", &self.base00),
+      ("base01", &self.base01),
+      ("base02", &self.base02),
+      ("base03", &self.base03),
+      ("base04", &self.base04),
+      ("base05", &self.base05),
+      ("base06", &self.base06),
+      ("base07", &self.base07),
+      ("base08", &self.base08),
+      ("base09", &self.base09),
+      ("base0A", &self.base0a),
+      ("base0B", &self.base0b),
+      ("base0C", &self.base0c),
+      ("base0D", &self.base0d),
+      ("base0E", &self.base0e),
+      ("base0F", &self.base0f),
+      ("base10", &self.base10),
+      ("base11", &self.base11),
+      ("base12", &self.base12),
+      ("base13", &self.base13),
+      ("base14", &self.base14),
+      ("base15", &self.base15),
+      ("base16", &self.base16),
+      ("base17", &self.base17),
+    ]
+    .into_iter()
+  }
+}
+
+impl<T> AnsiMain<T> {
+  pub fn map<U>(self, f: impl Fn(T) -> U) -> AnsiMain<U> {
+    AnsiMain {
+      black: f(self.black),
+      red: f(self.red),
+      green: f(self.green),
+      blue: f(self.blue),
+      cyan: f(self.cyan),
+      yellow: f(self.yellow),
+      magenta: f(self.magenta),
+      white: f(self.white),
+      bright_black: f(self.bright_black),
+      bright_red: f(self.bright_red),
+      bright_green: f(self.bright_green),
+      bright_blue: f(self.bright_blue),
+      bright_cyan: f(self.bright_cyan),
+      bright_yellow: f(self.bright_yellow),
+      bright_magenta: f(self.bright_magenta),
+      bright_white: f(self.bright_white),
+    }
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&'static str, &T)> {
+    [
+      ("black", &self.black),
+      ("red", &self.red),
+      ("green", &self.green),
+      ("blue", &self.blue),
+      ("cyan", &self.cyan),
+      ("yellow", &self.yellow),
+      ("magenta", &self.magenta),
+      ("white", &self.white),
+      ("bright_black", &self.bright_black),
+      ("bright_red", &self.bright_red),
+      ("bright_green", &self.bright_green),
+      ("bright_blue", &self.bright_blue),
+      ("bright_cyan", &self.bright_cyan),
+      ("bright_yellow", &self.bright_yellow),
+      ("bright_magenta", &self.bright_magenta),
+      ("bright_white", &self.bright_white),
+    ]
+    .into_iter()
+  }
+}
+
+impl Rgba {
+  pub fn hex(&self) -> String {
+    let Rgba {
+      red, green, blue, ..
+    } = self;
+    format!("#{red:02X}{green:02X}{blue:02X}")
+  }
+
+  pub fn hue(&self) -> f32 {
+    let Rgba {
+      red,
+      green,
+      blue,
+      alpha,
+    } = self.clone();
+    let lch = palette::IntoColor::<palette::Oklcha>::into_color(
+      palette::Alpha::<palette::LinSrgb<u8>, f32>::new(red, green, blue, alpha)
+        .into_format::<f32, f32>(),
+    );
+
+    lch.hue.into_positive_degrees()
+  }
+
+  // NOTE: WCAG 2 relative luminance
+  pub fn luminance(&self) -> f32 {
+    crate::extrapolate::contrast::luminance((self.red, self.green, self.blue))
+  }
+
+  // NOTE: WCAG 2 contrast ratio
+  pub fn contrast(&self, other: &Rgba) -> f32 {
+    crate::extrapolate::contrast::wcag(
+      (self.red, self.green, self.blue),
+      (other.red, other.green, other.blue),
+    )
+  }
+
+  pub fn readable(&self) -> Rgba {
+    let value = if self.luminance() > 0.179f32 { 0 } else { 255 };
+    Rgba {
+      red: value,
+      green: value,
+      blue: value,
+      alpha: 1.0f32,
+    }
+  }
+}
+
+// NOTE: shared by the list and grid formats
+fn semantic_one(
+  painter: &term::Painter,
+  name: &str,
+  color: &Rgba,
+) -> anyhow::Result<()> {
+  let Rgba {
+    red: r,
+    green: g,
+    blue: b,
+    alpha: a,
+  } = color.clone();
+
+  std::io::stdout().write_all(
+    format!(
+      "{name}: {}\n",
+      painter.fg(format!("rgba({r}, {g}, {b}, {a})").as_str(), color)
+    )
+    .as_bytes(),
+  )?;
+
+  Ok(())
+}
+
+fn source_one(
+  painter: &term::Painter,
+  source: &Source,
+  picks: Vec<&str>,
+) -> anyhow::Result<()> {
+  let Rgba {
+    red: r,
+    green: g,
+    blue: b,
+    alpha: a,
+  } = source.color.clone();
+  let weight = source.weight * 100.0f32;
+  let picks = if picks.is_empty() {
+    String::new()
+  } else {
+    format!(" <- {}", picks.join(", "))
+  };
+
+  std::io::stdout().write_all(
+    format!(
+      "{}{picks}\n",
+      painter.fg(
+        format!("rgba({r}, {g}, {b}, {a}) {weight:.2}%").as_str(),
+        &source.color
+      )
+    )
+    .as_bytes(),
+  )?;
+
+  Ok(())
+}
+
+// NOTE: shared by the list and grid formats
+fn pinned_one(pinned: Vec<&str>) -> anyhow::Result<()> {
+  if pinned.is_empty() {
+    return Ok(());
+  }
+
+  std::io::stdout()
+    .write_all(format!("pinned -> {}\n", pinned.join(", ")).as_bytes())?;
+
+  Ok(())
+}
+
+async fn write(output: Option<String>, bytes: Vec<u8>) -> anyhow::Result<()> {
+  match output {
+    Some(output) => {
+      let dirname = std::path::Path::new(&output).parent();
+      if let Some(dirname) = dirname {
+        if !dirname.as_os_str().is_empty()
+          && !tokio::fs::try_exists(dirname).await?
+        {
+          tokio::fs::create_dir_all(dirname).await?;
+        }
+      }
+      tokio::fs::write(output, bytes).await?;
+    }
+    None => {
+      std::io::Write::write_all(&mut std::io::stdout(), bytes.as_slice())?;
+    }
+  }
+
+  Ok(())
+}
+
+async fn thumbnail(
+  path: String,
+  width: u32,
+) -> anyhow::Result<image::RgbaImage> {
+  tokio::spawn(async move {
+    let image = image::io::Reader::open(path)?.decode()?;
+    let height = image.height() * width / image.width().max(1);
+    anyhow::Ok(image.thumbnail(width, height.max(1)).to_rgba8())
+  })
+  .await?
+}
+
+fn encode(image: &image::RgbaImage) -> anyhow::Result<Vec<u8>> {
+  let mut bytes = std::io::Cursor::new(Vec::new());
+  image.write_to(&mut bytes, image::ImageOutputFormat::Png)?;
+
+  Ok(bytes.into_inner())
+}
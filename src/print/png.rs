@@ -0,0 +1,85 @@
+use super::font;
+
+#[derive(Debug, Clone)]
+pub struct PngConfig {
+  pub output: Option<String>,
+  pub thumbnail: Option<String>,
+}
+
+#[tracing::instrument(skip(colors))]
+pub async fn from(
+  colors: super::Colors,
+  config: PngConfig,
+) -> anyhow::Result<()> {
+  let sheet = super::sheet::layout(&colors, config.thumbnail).await?;
+
+  let mut image = image::RgbaImage::from_pixel(
+    sheet.width,
+    sheet.height,
+    pixel(&sheet.background),
+  );
+
+  for swatch in sheet.swatches {
+    fill(
+      &mut image,
+      swatch.x,
+      swatch.y,
+      swatch.width,
+      swatch.height,
+      &swatch.color,
+    );
+  }
+
+  for label in sheet.labels {
+    let mut x = label.x;
+    for character in label.text.chars() {
+      let glyph = font::glyph(character);
+      for (row, bits) in glyph.iter().enumerate() {
+        for column in 0..font::WIDTH {
+          if bits & (1 << (font::WIDTH - 1 - column)) != 0 {
+            fill(
+              &mut image,
+              x + column * label.scale,
+              label.y + row as u32 * label.scale,
+              label.scale,
+              label.scale,
+              &label.color,
+            );
+          }
+        }
+      }
+      x += font::ADVANCE * label.scale;
+    }
+  }
+
+  if let Some(thumbnail) = sheet.thumbnail {
+    image::imageops::overlay(
+      &mut image,
+      &thumbnail.image,
+      thumbnail.x.into(),
+      thumbnail.y.into(),
+    );
+  }
+
+  super::write(config.output, super::encode(&image)?).await
+}
+
+fn fill(
+  image: &mut image::RgbaImage,
+  x: u32,
+  y: u32,
+  width: u32,
+  height: u32,
+  color: &super::Rgba,
+) {
+  let pixel = pixel(color);
+  for y in y..(y + height).min(image.height()) {
+    for x in x..(x + width).min(image.width()) {
+      image.put_pixel(x, y, pixel);
+    }
+  }
+}
+
+fn pixel(color: &super::Rgba) -> image::Rgba<u8> {
+  image::Rgba([color.red, color.green, color.blue, 255])
+}
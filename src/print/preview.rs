@@ -0,0 +1,292 @@
+use std::io::Write;
+
+const WIDTH: usize = 72;
+
+#[derive(Debug, Clone, Copy)]
+enum Style {
+  Normal,
+  Bold,
+}
+
+struct Span<'a> {
+  color: &'a super::Rgba,
+  style: Style,
+  text: String,
+}
+
+fn span(color: &super::Rgba, text: impl Into<String>) -> Span<'_> {
+  Span {
+    color,
+    style: Style::Normal,
+    text: text.into(),
+  }
+}
+
+fn bold(color: &super::Rgba, text: impl Into<String>) -> Span<'_> {
+  Span {
+    color,
+    style: Style::Bold,
+    text: text.into(),
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviewConfig {
+  pub color: super::ColorMode,
+  pub simulate: Option<crate::extrapolate::cvd::Deficiency>,
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn from(
+  mut colors: super::Colors,
+  config: PreviewConfig,
+) -> anyhow::Result<()> {
+  let painter = super::term::Painter::new(config.color);
+  if let Some(deficiency) = config.simulate {
+    colors.ansi.main =
+      colors.ansi.main.map(|color| simulate(&color, deficiency));
+    colors.semantic.background =
+      simulate(&colors.semantic.background, deficiency);
+    colors.semantic.foreground =
+      simulate(&colors.semantic.foreground, deficiency);
+  }
+  let main = &colors.ansi.main;
+  // NOTE: same background and foreground as the emulator templates
+  let background = &colors.semantic.background;
+  let foreground = &colors.semantic.foreground;
+
+  let mut lines = Vec::new();
+  let prompt = |command: &str| {
+    vec![
+      bold(&main.green, "user@host"),
+      span(foreground, ":"),
+      bold(&main.blue, "~/lulezojne"),
+      span(foreground, "$ "),
+      span(foreground, command),
+    ]
+  };
+
+  lines.push(prompt("ls -l"));
+  for (permissions, size, name, color, style) in [
+    ("drwxr-xr-x", "4.0K", "assets/", &main.blue, Style::Bold),
+    ("drwxr-xr-x", "4.0K", "src/", &main.blue, Style::Bold),
+    (
+      "-rw-r--r--",
+      "1.2K",
+      "Cargo.toml",
+      foreground,
+      Style::Normal,
+    ),
+    ("-rw-r--r--", "1.1K", "LICENSE", foreground, Style::Normal),
+    ("-rwxr-xr-x", "312", "build.sh", &main.green, Style::Bold),
+    (
+      "lrwxrwxrwx",
+      "11",
+      "result -> /nix/store",
+      &main.cyan,
+      Style::Bold,
+    ),
+    (
+      "-rw-r--r--",
+      "2.3M",
+      "wallpaper.png",
+      &main.magenta,
+      Style::Bold,
+    ),
+    (
+      "-rw-r--r--",
+      "8.4M",
+      "backup.tar.gz",
+      &main.red,
+      Style::Bold,
+    ),
+    (
+      "prw-r--r--",
+      "0",
+      "events.fifo",
+      &main.yellow,
+      Style::Normal,
+    ),
+  ] {
+    lines.push(vec![
+      span(&main.bright_black, format!("{permissions} user user ")),
+      span(&main.bright_green, format!("{size:>5} ")),
+      span(&main.bright_blue, "Sep 18 12:00 "),
+      Span {
+        color,
+        style,
+        text: name.to_owned(),
+      },
+    ]);
+  }
+  lines.push(Vec::new());
+
+  lines.push(prompt("cat src/main.rs"));
+  lines.push(vec![span(&main.bright_black, "// Prints a greeting")]);
+  lines.push(vec![
+    span(&main.magenta, "use "),
+    span(foreground, "std::io::"),
+    span(&main.yellow, "Write"),
+    span(foreground, ";"),
+  ]);
+  lines.push(Vec::new());
+  lines.push(vec![
+    span(&main.magenta, "fn "),
+    span(&main.blue, "main"),
+    span(foreground, "() -> "),
+    span(&main.yellow, "Result"),
+    span(foreground, "<(), "),
+    span(&main.yellow, "Error"),
+    span(foreground, "> {"),
+  ]);
+  lines.push(vec![
+    span(foreground, "  "),
+    span(&main.magenta, "let "),
+    span(foreground, "count = "),
+    span(&main.bright_yellow, "42"),
+    span(foreground, ";"),
+  ]);
+  lines.push(vec![
+    span(foreground, "  "),
+    span(&main.cyan, "println!"),
+    span(foreground, "("),
+    span(&main.green, "\"Hello, {count} colors!\""),
+    span(foreground, ");"),
+  ]);
+  lines.push(vec![
+    span(foreground, "  "),
+    span(&main.yellow, "Ok"),
+    span(foreground, "(())"),
+  ]);
+  lines.push(vec![span(foreground, "}")]);
+  lines.push(Vec::new());
+
+  lines.push(prompt("git diff"));
+  lines.push(vec![bold(
+    &main.bright_white,
+    "diff --git a/config.toml b/config.toml",
+  )]);
+  lines.push(vec![span(
+    &main.bright_black,
+    "index 3b18e51..a9c2f07 100644",
+  )]);
+  lines.push(vec![bold(&main.bright_white, "--- a/config.toml")]);
+  lines.push(vec![bold(&main.bright_white, "+++ b/config.toml")]);
+  lines.push(vec![
+    span(&main.cyan, "@@ -1,4 +1,4 @@"),
+    span(foreground, " [[plop]]"),
+  ]);
+  lines.push(vec![span(foreground, " in = \"/dev/stdout\"")]);
+  lines.push(vec![span(&main.red, "-backend = \"kmeans\"")]);
+  lines.push(vec![span(&main.green, "+backend = \"neoquant\"")]);
+  lines.push(vec![span(foreground, " then = { command = \"echo\" }")]);
+  lines.push(Vec::new());
+
+  lines.push(prompt("lulezojne print --format preview"));
+  for (name, color) in colors.ansi.main.iter() {
+    lines.push(vec![
+      span(color, format!("{name:<16}")),
+      bold(color, "bold text "),
+      span(&main.bright_black, "on background"),
+    ]);
+  }
+
+  let mut output = String::new();
+  for line in lines {
+    output.push_str(one(&painter, line, background).as_str());
+  }
+  output.push('\n');
+  output.push_str(swatches(&painter, &colors).as_str());
+
+  std::io::stdout().write_all(output.as_bytes())?;
+
+  Ok(())
+}
+
+fn simulate(
+  color: &super::Rgba,
+  deficiency: crate::extrapolate::cvd::Deficiency,
+) -> super::Rgba {
+  let [red, green, blue] = crate::extrapolate::cvd::simulate(
+    [color.red, color.green, color.blue]
+      .map(|channel| Into::<f32>::into(channel) / 255.0f32),
+    deficiency,
+  )
+  .map(|channel| (channel * 255.0f32).round() as u8);
+
+  super::Rgba {
+    red,
+    green,
+    blue,
+    alpha: color.alpha,
+  }
+}
+
+fn one(
+  painter: &super::term::Painter,
+  spans: Vec<Span>,
+  background: &super::Rgba,
+) -> String {
+  let mut line = painter.paint(
+    " ",
+    super::term::Style {
+      background: Some(background),
+      ..Default::default()
+    },
+  );
+  let mut length = 0;
+  for Span { color, style, text } in spans {
+    length += text.chars().count();
+    line.push_str(
+      painter
+        .paint(
+          text.as_str(),
+          super::term::Style {
+            foreground: Some(color),
+            background: Some(background),
+            bold: matches!(style, Style::Bold),
+          },
+        )
+        .as_str(),
+    );
+  }
+
+  line.push_str(
+    painter
+      .paint(
+        " ".repeat(WIDTH.saturating_sub(length) + 1).as_str(),
+        super::term::Style {
+          background: Some(background),
+          ..Default::default()
+        },
+      )
+      .as_str(),
+  );
+  line.push('\n');
+
+  line
+}
+
+fn swatches(painter: &super::term::Painter, colors: &super::Colors) -> String {
+  let mut output = String::new();
+  for row in [0, 8] {
+    for (_, color) in colors.ansi.main.iter().skip(row).take(8) {
+      output.push_str(
+        painter
+          .paint(
+            format!(" {:^7} ", color.hex()).as_str(),
+            super::term::Style {
+              foreground: Some(&color.readable()),
+              background: Some(color),
+              bold: false,
+            },
+          )
+          .as_str(),
+      );
+    }
+    output.push('\n');
+  }
+  output.push('\n');
+
+  output
+}
@@ -0,0 +1,166 @@
+use super::font;
+
+// NOTE: shared layout for the png and svg swatch sheets
+
+const PADDING: u32 = 16;
+const TITLE_SCALE: u32 = 3;
+const LABEL_SCALE: u32 = 2;
+const MAIN_COLUMNS: u32 = 8;
+const MAIN_WIDTH: u32 = 180;
+const MAIN_HEIGHT: u32 = 100;
+const GRID_WIDTH: u32 = MAIN_COLUMNS * MAIN_WIDTH;
+const GRADIENT_COLUMNS: u32 = 36;
+const GRADIENT_HEIGHT: u32 = GRID_WIDTH / GRADIENT_COLUMNS;
+const GRAYSCALE_HEIGHT: u32 = 60;
+const THUMBNAIL_WIDTH: u32 = 360;
+
+#[derive(Debug, Clone)]
+pub struct Sheet {
+  pub width: u32,
+  pub height: u32,
+  pub background: super::Rgba,
+  pub swatches: Vec<Swatch>,
+  pub labels: Vec<Label>,
+  pub thumbnail: Option<Thumbnail>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Swatch {
+  pub x: u32,
+  pub y: u32,
+  pub width: u32,
+  pub height: u32,
+  pub color: super::Rgba,
+}
+
+#[derive(Debug, Clone)]
+pub struct Label {
+  pub x: u32,
+  pub y: u32,
+  pub scale: u32,
+  pub text: String,
+  pub color: super::Rgba,
+}
+
+#[derive(Debug, Clone)]
+pub struct Thumbnail {
+  pub x: u32,
+  pub y: u32,
+  pub image: image::RgbaImage,
+}
+
+#[tracing::instrument(skip(colors))]
+pub async fn layout(
+  colors: &super::Colors,
+  thumbnail: Option<String>,
+) -> anyhow::Result<Sheet> {
+  let thumbnail = match thumbnail {
+    Some(path) => Some(super::thumbnail(path, THUMBNAIL_WIDTH).await?),
+    None => None,
+  };
+
+  let background = colors.ansi.main.black.clone();
+  let foreground = background.readable();
+  let left = match &thumbnail {
+    Some(thumbnail) => PADDING + thumbnail.width() + PADDING,
+    None => PADDING,
+  };
+
+  let mut swatches = Vec::new();
+  let mut labels = Vec::new();
+  let mut top = PADDING;
+
+  let mut title = |text: &str, top: &mut u32| {
+    labels.push(Label {
+      x: left,
+      y: *top,
+      scale: TITLE_SCALE,
+      text: text.to_owned(),
+      color: foreground.clone(),
+    });
+    *top += font::HEIGHT * TITLE_SCALE + PADDING;
+  };
+
+  title("main", &mut top);
+  let main_top = top;
+  top += 2 * MAIN_HEIGHT + PADDING;
+
+  title("gradient", &mut top);
+  let gradient_top = top;
+  let gradient_rows =
+    (colors.ansi.gradient.len() as u32).div_ceil(GRADIENT_COLUMNS);
+  top += gradient_rows * GRADIENT_HEIGHT + PADDING;
+
+  title("grayscale", &mut top);
+  let grayscale_top = top;
+  top += GRAYSCALE_HEIGHT + PADDING;
+
+  for (index, (name, color)) in colors.ansi.main.iter().enumerate() {
+    let index = index as u32;
+    let x = left + (index % MAIN_COLUMNS) * MAIN_WIDTH;
+    let y = main_top + (index / MAIN_COLUMNS) * MAIN_HEIGHT;
+    swatches.push(Swatch {
+      x,
+      y,
+      width: MAIN_WIDTH,
+      height: MAIN_HEIGHT,
+      color: color.clone(),
+    });
+    labels.push(Label {
+      x: x + font::ADVANCE,
+      y: y + font::ADVANCE,
+      scale: LABEL_SCALE,
+      text: name.to_owned(),
+      color: color.readable(),
+    });
+    labels.push(Label {
+      x: x + font::ADVANCE,
+      y: y + MAIN_HEIGHT - font::ADVANCE - font::HEIGHT * LABEL_SCALE,
+      scale: LABEL_SCALE,
+      text: color.hex(),
+      color: color.readable(),
+    });
+  }
+
+  let gradient_width = GRID_WIDTH / GRADIENT_COLUMNS;
+  for (index, color) in colors.ansi.gradient.iter().enumerate() {
+    let index = index as u32;
+    swatches.push(Swatch {
+      x: left + (index % GRADIENT_COLUMNS) * gradient_width,
+      y: gradient_top + (index / GRADIENT_COLUMNS) * GRADIENT_HEIGHT,
+      width: gradient_width,
+      height: GRADIENT_HEIGHT,
+      color: color.clone(),
+    });
+  }
+
+  let grayscale_width =
+    GRID_WIDTH / (colors.ansi.grayscale.len() as u32).max(1);
+  for (index, color) in colors.ansi.grayscale.iter().enumerate() {
+    swatches.push(Swatch {
+      x: left + index as u32 * grayscale_width,
+      y: grayscale_top,
+      width: grayscale_width,
+      height: GRAYSCALE_HEIGHT,
+      color: color.clone(),
+    });
+  }
+
+  let height = match &thumbnail {
+    Some(thumbnail) => top.max(PADDING + thumbnail.height() + PADDING),
+    None => top,
+  };
+
+  Ok(Sheet {
+    width: left + GRID_WIDTH + PADDING,
+    height,
+    background,
+    swatches,
+    labels,
+    thumbnail: thumbnail.map(|image| Thumbnail {
+      x: PADDING,
+      y: PADDING,
+      image,
+    }),
+  })
+}
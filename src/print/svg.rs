@@ -0,0 +1,94 @@
+use base64::Engine;
+
+use super::font;
+
+#[derive(Debug, Clone)]
+pub struct SvgConfig {
+  pub output: Option<String>,
+  pub thumbnail: Option<String>,
+}
+
+#[tracing::instrument(skip(colors))]
+pub async fn from(
+  colors: super::Colors,
+  config: SvgConfig,
+) -> anyhow::Result<()> {
+  let sheet = super::sheet::layout(&colors, config.thumbnail).await?;
+  let width = sheet.width;
+  let height = sheet.height;
+
+  let mut svg = String::new();
+  svg.push_str(
+    format!(
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+        width=\"{width}\" height=\"{height}\" \
+        viewBox=\"0 0 {width} {height}\">\n"
+    )
+    .as_str(),
+  );
+  svg.push_str(
+    format!(
+      "  <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+      sheet.background.hex()
+    )
+    .as_str(),
+  );
+
+  if let Some(thumbnail) = sheet.thumbnail {
+    let data = base64::engine::general_purpose::STANDARD
+      .encode(super::encode(&thumbnail.image)?);
+    svg.push_str(
+      format!(
+        "  <image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+          href=\"data:image/png;base64,{data}\"/>\n",
+        thumbnail.x,
+        thumbnail.y,
+        thumbnail.image.width(),
+        thumbnail.image.height(),
+      )
+      .as_str(),
+    );
+  }
+
+  for swatch in sheet.swatches {
+    svg.push_str(
+      format!(
+        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        swatch.x,
+        swatch.y,
+        swatch.width,
+        swatch.height,
+        swatch.color.hex(),
+      )
+      .as_str(),
+    );
+  }
+
+  for label in sheet.labels {
+    // NOTE: monospace cap height is roughly 0.7 of the font size
+    let size = (font::HEIGHT * label.scale) as f32 / 0.7f32;
+    svg.push_str(
+      format!(
+        "  <text x=\"{}\" y=\"{}\" font-family=\"monospace\" \
+          font-size=\"{size:.1}\" fill=\"{}\">{}</text>\n",
+        label.x,
+        label.y + font::HEIGHT * label.scale,
+        label.color.hex(),
+        escape(label.text.to_uppercase().as_str()),
+      )
+      .as_str(),
+    );
+  }
+
+  svg.push_str("</svg>\n");
+
+  super::write(config.output, svg.into_bytes()).await
+}
+
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
@@ -0,0 +1,168 @@
+use std::io::IsTerminal;
+
+// NOTE: same tables as the extrapolated indexed colors
+use crate::extrapolate::ansi::{CUBE, XTERM};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Depth {
+  None,
+  Basic,
+  Indexed,
+  True,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Painter {
+  depth: Depth,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Style<'a> {
+  pub foreground: Option<&'a super::Rgba>,
+  pub background: Option<&'a super::Rgba>,
+  pub bold: bool,
+}
+
+impl Painter {
+  pub fn new(mode: super::ColorMode) -> Self {
+    Self {
+      depth: match mode {
+        super::ColorMode::Always => Depth::True,
+        super::ColorMode::Never => Depth::None,
+        super::ColorMode::Auto => detect(),
+        super::ColorMode::Indexed => Depth::Indexed,
+        super::ColorMode::Basic => Depth::Basic,
+      },
+    }
+  }
+
+  pub fn fg(&self, text: &str, color: &super::Rgba) -> String {
+    self.paint(
+      text,
+      Style {
+        foreground: Some(color),
+        ..Default::default()
+      },
+    )
+  }
+
+  pub fn paint(&self, text: &str, style: Style) -> String {
+    if self.depth == Depth::None {
+      return text.to_owned();
+    }
+
+    let mut codes = Vec::new();
+    if style.bold {
+      codes.push("1".to_owned());
+    }
+    if let Some(color) = style.foreground {
+      codes.push(self.code(color, false));
+    }
+    if let Some(color) = style.background {
+      codes.push(self.code(color, true));
+    }
+    if codes.is_empty() {
+      return text.to_owned();
+    }
+
+    format!("\x1b[{}m{text}\x1b[0m", codes.join(";"))
+  }
+
+  fn code(&self, color: &super::Rgba, background: bool) -> String {
+    let super::Rgba {
+      red, green, blue, ..
+    } = *color;
+    match self.depth {
+      Depth::True => format!(
+        "{};2;{red};{green};{blue}",
+        if background { 48 } else { 38 }
+      ),
+      Depth::Indexed => format!(
+        "{};5;{}",
+        if background { 48 } else { 38 },
+        indexed(red, green, blue)
+      ),
+      _ => {
+        let index = basic(red, green, blue);
+        let offset = match (background, index < 8) {
+          (false, true) => 30,
+          (false, false) => 90 - 8,
+          (true, true) => 40,
+          (true, false) => 100 - 8,
+        };
+        format!("{}", offset + index)
+      }
+    }
+  }
+}
+
+fn detect() -> Depth {
+  if !std::io::stdout().is_terminal() || std::env::var_os("NO_COLOR").is_some()
+  {
+    return Depth::None;
+  }
+
+  if let Ok(colorterm) = std::env::var("COLORTERM") {
+    if colorterm == "truecolor" || colorterm == "24bit" {
+      return Depth::True;
+    }
+  }
+
+  match std::env::var("TERM") {
+    Err(_) => Depth::None,
+    Ok(term) if term == "dumb" => Depth::None,
+    Ok(term) if term.contains("direct") || term.contains("truecolor") => {
+      Depth::True
+    }
+    Ok(term) if term.contains("256") => Depth::Indexed,
+    Ok(_) => Depth::Basic,
+  }
+}
+
+fn distance(lhs: (u8, u8, u8), rhs: (u8, u8, u8)) -> i32 {
+  let red = i32::from(lhs.0) - i32::from(rhs.0);
+  let green = i32::from(lhs.1) - i32::from(rhs.1);
+  let blue = i32::from(lhs.2) - i32::from(rhs.2);
+  red * red + green * green + blue * blue
+}
+
+fn indexed(red: u8, green: u8, blue: u8) -> u8 {
+  let level = |channel: u8| {
+    CUBE
+      .iter()
+      .enumerate()
+      .min_by_key(|(_, level)| (i32::from(**level) - i32::from(channel)).abs())
+      .map(|(index, _)| index as u8)
+      .unwrap_or_default()
+  };
+  let (red_level, green_level, blue_level) =
+    (level(red), level(green), level(blue));
+  let cube = (
+    CUBE[red_level as usize],
+    CUBE[green_level as usize],
+    CUBE[blue_level as usize],
+  );
+  let cube_index = 16 + 36 * red_level + 6 * green_level + blue_level;
+
+  let average = (u16::from(red) + u16::from(green) + u16::from(blue)) / 3;
+  let gray_level = (average.saturating_sub(3) / 10).min(23) as u8;
+  let gray = 8 + 10 * gray_level;
+  let gray_index = 232 + gray_level;
+
+  if distance((red, green, blue), (gray, gray, gray))
+    < distance((red, green, blue), cube)
+  {
+    gray_index
+  } else {
+    cube_index
+  }
+}
+
+fn basic(red: u8, green: u8, blue: u8) -> u8 {
+  XTERM
+    .iter()
+    .enumerate()
+    .min_by_key(|(_, color)| distance((red, green, blue), **color))
+    .map(|(index, _)| index as u8)
+    .unwrap_or_default()
+}